@@ -0,0 +1,145 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::{Cell, RefCell},
+};
+
+#[derive(Clone, Copy)]
+struct Counters {
+    // Memory freed by another thread than its allocator can make it negative
+    current: isize,
+    peak: isize,
+    allocations: usize,
+}
+
+thread_local! {
+    // Constant initialized without destructor, so the allocator can use it
+    // without allocating
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            current: 0,
+            peak: 0,
+            allocations: 0,
+        })
+    };
+    static PHASES: RefCell<Option<Vec<Phase>>> = const { RefCell::new(None) };
+}
+
+/// System allocator counting allocations and the bytes in use. Binaries
+/// install it with `#[global_allocator]`. The counters are per thread, so
+/// solvers running in parallel are measured separately.
+pub struct CountingAllocator;
+
+fn update(f: impl FnOnce(&mut Counters)) {
+    // Fails only while the thread is being torn down
+    let _ = COUNTERS.try_with(|counters| {
+        let mut value = counters.get();
+        f(&mut value);
+        counters.set(value);
+    });
+}
+
+fn allocated(size: usize) {
+    update(|counters| {
+        counters.allocations += 1;
+        counters.current += size as isize;
+        counters.peak = counters.peak.max(counters.current);
+    });
+}
+
+fn deallocated(size: usize) {
+    update(|counters| counters.current -= size as isize);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        deallocated(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            deallocated(layout.size());
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Highest number of bytes in use above the level at the start
+    pub peak_bytes: usize,
+    /// Number of allocations and reallocations
+    pub allocations: usize,
+}
+
+/// Statistics of a named part of a measured run, like parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Phase {
+    pub name: &'static str,
+    pub stats: AllocStats,
+}
+
+/// Runs `f` and collects its allocation statistics on the current thread.
+/// They are all zero unless the `CountingAllocator` is installed. Nested
+/// measurements don't disturb the enclosing one.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let start = COUNTERS.with(Cell::get);
+    update(|counters| counters.peak = counters.current);
+
+    let res = f();
+
+    let end = COUNTERS.with(Cell::get);
+    update(|counters| counters.peak = counters.peak.max(start.peak));
+
+    let stats = AllocStats {
+        peak_bytes: (end.peak - start.current).max(0) as usize,
+        allocations: end.allocations - start.allocations,
+    };
+
+    (res, stats)
+}
+
+/// Measures `f` as a phase of the enclosing `measure_phases`, if any.
+pub fn phase<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+    let (res, stats) = measure(f);
+
+    PHASES.with(|phases| {
+        if let Some(phases) = phases.borrow_mut().as_mut() {
+            phases.push(Phase { name, stats });
+        }
+    });
+
+    res
+}
+
+/// Like `measure`, also returning the phases `f` went through.
+pub fn measure_phases<T>(f: impl FnOnce() -> T) -> (T, AllocStats, Vec<Phase>) {
+    let outer = PHASES.with(|phases| phases.replace(Some(Vec::new())));
+    let (res, stats) = measure(f);
+    let phases = PHASES
+        .with(|phases| phases.replace(outer))
+        .unwrap_or_default();
+
+    (res, stats, phases)
+}
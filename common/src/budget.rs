@@ -0,0 +1,218 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{
+    fmt::Display,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+// Reading the clock on every step would dominate tight search loops
+const CLOCK_CHECK_INTERVAL: u64 = 1024;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Progress {
+    pub steps: u64,
+    pub elapsed: Duration,
+    pub best: u64,
+}
+
+type ProgressCallback = Box<dyn FnMut(&Progress)>;
+
+/// Execution budget of a long running search.
+///
+/// Solvers call `step` once per unit of work and stop as soon as it returns
+/// false. The budget runs out when the time or step limit is reached or when
+/// the search is cancelled from another thread.
+pub struct Budget {
+    start: Instant,
+    time_limit: Option<Duration>,
+    step_limit: Option<u64>,
+    steps: u64,
+    best: u64,
+    exhausted: bool,
+    cancelled: Arc<AtomicBool>,
+    progress_interval: u64,
+    progress: Option<ProgressCallback>,
+}
+
+impl Budget {
+    pub fn unlimited() -> Self {
+        Self {
+            start: Instant::now(),
+            time_limit: None,
+            step_limit: None,
+            steps: 0,
+            best: 0,
+            exhausted: false,
+            cancelled: Arc::new(AtomicBool::new(false)),
+            progress_interval: 0,
+            progress: None,
+        }
+    }
+
+    /// Builds a budget from the `--time-limit <seconds>`, `--step-limit <steps>`
+    /// and `--progress <steps>` command line arguments. Progress is reported on
+    /// stderr.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        let value_of = |name: &str| {
+            args.iter()
+                .position(|arg| arg == name)
+                .and_then(|index| args.get(index + 1))
+                .map(|value| {
+                    value
+                        .parse::<u64>()
+                        .unwrap_or_else(|_| panic!("Invalid value for {}: {}", name, value))
+                })
+        };
+
+        let mut budget = Self::unlimited();
+
+        if let Some(seconds) = value_of("--time-limit") {
+            budget = budget.with_time_limit(Duration::from_secs(seconds));
+        }
+
+        if let Some(steps) = value_of("--step-limit") {
+            budget = budget.with_step_limit(steps);
+        }
+
+        if let Some(interval) = value_of("--progress") {
+            budget = budget.with_progress(interval, |progress| {
+                eprintln!(
+                    "{} steps, {:.1?} elapsed, best so far: {}",
+                    progress.steps, progress.elapsed, progress.best
+                )
+            });
+        }
+
+        budget
+    }
+
+    pub fn with_time_limit(mut self, limit: Duration) -> Self {
+        self.time_limit = Some(limit);
+        self
+    }
+
+    pub fn with_step_limit(mut self, limit: u64) -> Self {
+        self.step_limit = Some(limit);
+        self
+    }
+
+    /// Calls `callback` after every `interval` steps.
+    pub fn with_progress<F: FnMut(&Progress) + 'static>(
+        mut self,
+        interval: u64,
+        callback: F,
+    ) -> Self {
+        self.progress_interval = interval;
+        self.progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Flag which cancels the search when set, e.g. from a signal handler or
+    /// another thread.
+    pub fn cancel_flag(&self) -> Arc<AtomicBool> {
+        self.cancelled.clone()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    pub fn best(&self) -> u64 {
+        self.best
+    }
+
+    /// Records the best result found so far for progress reports.
+    pub fn set_best(&mut self, best: u64) {
+        self.best = best;
+    }
+
+    /// Accounts one unit of work. Returns false if the search has to stop.
+    pub fn step(&mut self) -> bool {
+        if self.exhausted {
+            return false;
+        }
+
+        self.steps += 1;
+
+        if self.progress_interval != 0 && self.steps.is_multiple_of(self.progress_interval) {
+            let progress = Progress {
+                steps: self.steps,
+                elapsed: self.elapsed(),
+                best: self.best,
+            };
+            if let Some(callback) = self.progress.as_mut() {
+                callback(&progress);
+            }
+        }
+
+        if self.step_limit.is_some_and(|limit| self.steps > limit)
+            || self.cancelled.load(Ordering::Relaxed)
+            || (self.steps.is_multiple_of(CLOCK_CHECK_INTERVAL)
+                && self.time_limit.is_some_and(|limit| self.elapsed() > limit))
+        {
+            self.exhausted = true;
+        }
+
+        !self.exhausted
+    }
+
+    /// Wraps the result of the search depending on whether the budget was
+    /// sufficient.
+    pub fn outcome<T>(&self, result: T) -> Outcome<T> {
+        if self.exhausted {
+            Outcome::TimedOut(result)
+        } else {
+            Outcome::Finished(result)
+        }
+    }
+}
+
+/// Result of a search that may run out of its budget. A timed out search
+/// holds the best result found so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Finished(T),
+    TimedOut(T),
+}
+
+impl<T> Outcome<T> {
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Outcome<U> {
+        match self {
+            Outcome::Finished(value) => Outcome::Finished(f(value)),
+            Outcome::TimedOut(value) => Outcome::TimedOut(f(value)),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Outcome::Finished(_))
+    }
+
+    pub fn value(self) -> T {
+        match self {
+            Outcome::Finished(value) | Outcome::TimedOut(value) => value,
+        }
+    }
+}
+
+impl<T: Display> Display for Outcome<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Finished(value) => write!(f, "{}", value),
+            Outcome::TimedOut(value) => write!(f, "timed out (best so far: {})", value),
+        }
+    }
+}
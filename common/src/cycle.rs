@@ -0,0 +1,112 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Cycle of a state sequence: the state after `start + length` steps equals
+/// the state after `start` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// Brent's algorithm on the sequence `x0, f(x0), f(f(x0)), ...`, keeping only
+/// two states alive at a time.
+pub fn brent<T: Clone + PartialEq>(x0: T, mut f: impl FnMut(&T) -> T) -> Cycle {
+    // Find the length with power of two sized windows
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = x0.clone();
+    let mut hare = f(&x0);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = f(&hare);
+        length += 1;
+    }
+
+    // The hare is `length` steps ahead, they meet at the start of the cycle
+    let mut tortoise = x0.clone();
+    let mut hare = x0;
+    for _ in 0..length {
+        hare = f(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Floyd's tortoise and hare algorithm on the sequence
+/// `x0, f(x0), f(f(x0)), ...`.
+pub fn floyd<T: Clone + PartialEq>(x0: T, mut f: impl FnMut(&T) -> T) -> Cycle {
+    let mut tortoise = f(&x0);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        let next = f(&hare);
+        hare = f(&next);
+    }
+
+    let mut start = 0;
+    let mut tortoise = x0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        length += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Remembers every state of the sequence until one repeats. Useful when the
+/// states are expensive to recompute, or when only a summary of the full state
+/// is hashed. Returns `None` if the sequence ends without a cycle.
+pub fn find<T: Hash + Eq>(states: impl IntoIterator<Item = T>) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+
+    for (step, state) in states.into_iter().enumerate() {
+        if let Some(start) = seen.insert(state, step) {
+            return Some(Cycle {
+                start,
+                length: step - start,
+            });
+        }
+    }
+
+    None
+}
+
+impl Cycle {
+    /// Value of a metric after `steps` steps, where `metrics[i]` holds the
+    /// value after `i` steps up to at least `start + length` steps and the
+    /// metric changes by the same amount in every round of the cycle.
+    pub fn extrapolate(&self, metrics: &[i64], steps: u64) -> i64 {
+        if let Some(metric) = metrics.get(steps as usize) {
+            return *metric;
+        }
+
+        let start = self.start as u64;
+        let length = self.length as u64;
+        let rounds = ((steps - start) / length) as i64;
+        let offset = ((steps - start) % length) as usize;
+        let change = metrics[self.start + self.length] - metrics[self.start];
+
+        metrics[self.start + offset] + rounds * change
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::path::PathBuf;
+
+/// Puzzle input of the calling day crate. The file is embedded into the binary
+/// when the crate's `embed-inputs` feature is enabled, otherwise it is read at
+/// runtime, see `path`.
+#[macro_export]
+macro_rules! input {
+    ($name:literal) => {{
+        #[cfg(feature = "embed-inputs")]
+        let input: std::borrow::Cow<'static, str> = std::borrow::Cow::Borrowed(include_str!($name));
+        #[cfg(not(feature = "embed-inputs"))]
+        let input: std::borrow::Cow<'static, str> = std::borrow::Cow::Owned($crate::input::load(
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_MANIFEST_DIR"),
+            $name,
+        ));
+        input
+    }};
+}
+
+/// The committed test fixtures, like `input_test`, always belong to the sources.
+fn is_fixture(name: &str) -> bool {
+    name.contains("test")
+}
+
+/// File of the day crate `package`: `$AOC_INPUT_DIR/<package>/<name>` if the
+/// variable is set, so personal inputs can be kept outside of the repository,
+/// otherwise the crate's `src` directory.
+pub fn path(package: &str, manifest_dir: &str, name: &str) -> PathBuf {
+    match std::env::var_os("AOC_INPUT_DIR") {
+        Some(dir) if !is_fixture(name) => PathBuf::from(dir).join(package).join(name),
+        _ => PathBuf::from(manifest_dir).join("src").join(name),
+    }
+}
+
+pub fn load(package: &str, manifest_dir: &str, name: &str) -> String {
+    let path = path(package, manifest_dir, name);
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read input {}: {}", path.display(), e))
+}
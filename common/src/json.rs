@@ -0,0 +1,251 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{iter::Peekable, str::Chars};
+
+// Deeper documents are rejected instead of overflowing the stack
+const MAX_DEPTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    /// The number as written, so integers of any size can be read exactly
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => n.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Number(n) => n.parse().ok().or_else(|| {
+                let n = self.as_f64()?;
+                (n.fract() == 0.0).then_some(n as i64)
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&[(String, Value)]> {
+        match self {
+            Value::Object(fields) => Some(fields),
+            _ => None,
+        }
+    }
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_ascii_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.chars.next() {
+            Some(c) if c == expected => Ok(()),
+            c => Err(format!("Expected {:?}, found {:?}", expected, c)),
+        }
+    }
+
+    fn keyword(&mut self, word: &str, value: Value) -> Result<Value, String> {
+        for expected in word.chars() {
+            if self.chars.next() != Some(expected) {
+                return Err(format!("Invalid literal, expected {}", word));
+            }
+        }
+        Ok(value)
+    }
+
+    /// The four hex digits of a `\u` escape.
+    fn code_unit(chars: &mut Peekable<Chars>) -> Option<u32> {
+        let code: String = (0..4).filter_map(|_| chars.next()).collect();
+        u32::from_str_radix(&code, 16).ok()
+    }
+
+    /// Character of a `\u` escape, characters outside of the basic plane are
+    /// escaped as a surrogate pair.
+    fn escaped_char(&mut self) -> char {
+        let Some(high) = Self::code_unit(&mut self.chars) else {
+            return char::REPLACEMENT_CHARACTER;
+        };
+
+        if (0xd800..0xdc00).contains(&high) {
+            let mut ahead = self.chars.clone();
+            if ahead.next() == Some('\\') && ahead.next() == Some('u') {
+                if let Some(low @ 0xdc00..0xe000) = Self::code_unit(&mut ahead) {
+                    self.chars = ahead;
+                    let code = 0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00);
+                    return char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER);
+                }
+            }
+        }
+
+        char::from_u32(high).unwrap_or(char::REPLACEMENT_CHARACTER)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+
+        let mut res = String::new();
+        loop {
+            match self.chars.next() {
+                Some('"') => return Ok(res),
+                Some('\\') => match self.chars.next() {
+                    Some('n') => res.push('\n'),
+                    Some('r') => res.push('\r'),
+                    Some('t') => res.push('\t'),
+                    Some('b') => res.push('\u{8}'),
+                    Some('f') => res.push('\u{c}'),
+                    Some('u') => res.push(self.escaped_char()),
+                    Some(c) => res.push(c),
+                    None => return Err("Unterminated string".to_string()),
+                },
+                Some(c) => res.push(c),
+                None => return Err("Unterminated string".to_string()),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let mut text = String::new();
+        while let Some(c) = self
+            .chars
+            .next_if(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            text.push(c);
+        }
+
+        match text.parse::<f64>() {
+            Ok(_) => Ok(Value::Number(text)),
+            Err(_) => Err(format!("Invalid number {}", text)),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, String> {
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&'}').is_some() {
+            return Ok(Value::Object(fields));
+        }
+        loop {
+            let key = self.string()?;
+            self.expect(':')?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some(',') => self.skip_whitespace(),
+                Some('}') => return Ok(Value::Object(fields)),
+                c => return Err(format!("Expected ',' or '}}', found {:?}", c)),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&']').is_some() {
+            return Ok(Value::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some(',') => {}
+                Some(']') => return Ok(Value::Array(items)),
+                c => return Err(format!("Expected ',' or ']', found {:?}", c)),
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some('{' | '[') => {
+                if self.depth == MAX_DEPTH {
+                    return Err(format!("Nested deeper than {} levels", MAX_DEPTH));
+                }
+
+                self.depth += 1;
+                let res = match self.chars.next() {
+                    Some('{') => self.object(),
+                    _ => self.array(),
+                };
+                self.depth -= 1;
+                res
+            }
+            Some('"') => self.string().map(Value::String),
+            Some('t') => self.keyword("true", Value::Bool(true)),
+            Some('f') => self.keyword("false", Value::Bool(false)),
+            Some('n') => self.keyword("null", Value::Null),
+            Some(_) => self.number(),
+            None => Err("Unexpected end of input".to_string()),
+        }
+    }
+}
+
+pub fn parse(text: &str) -> Result<Value, String> {
+    let mut parser = Parser {
+        chars: text.chars().peekable(),
+        depth: 0,
+    };
+
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    match parser.chars.next() {
+        None => Ok(value),
+        Some(c) => Err(format!("Unexpected {:?} after the value", c)),
+    }
+}
+
+/// JSON string literal of `value`.
+pub fn string(value: &str) -> String {
+    let mut res = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if (c as u32) < 0x20 => res.push_str(&format!("\\u{:04x}", c as u32)),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
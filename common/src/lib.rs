@@ -0,0 +1,14 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+pub mod alloc;
+pub mod budget;
+pub mod cycle;
+pub mod input;
+pub mod interner;
+pub mod json;
+pub mod memo;
+pub mod number;
+pub mod overflow;
+pub mod parse;
+pub mod snapshot;
@@ -0,0 +1,98 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::{
+    cycle::{self, Cycle},
+    json::{self, Value},
+    number::{checked_lcm_all, crt, extended_gcd, gcd_all, Mod},
+};
+
+fn main() {
+    let rotate = |x: &u32| (x + 1) % 12;
+    assert_eq!(
+        Cycle {
+            start: 0,
+            length: 12
+        },
+        cycle::brent(0, rotate)
+    );
+    assert_eq!(cycle::brent(0, rotate), cycle::floyd(0, rotate));
+
+    let square = |x: &u32| (x * x + 1) % 255;
+    assert_eq!(
+        Cycle {
+            start: 2,
+            length: 6
+        },
+        cycle::floyd(3, square)
+    );
+    assert_eq!(cycle::brent(3, square), cycle::floyd(3, square));
+    assert_eq!(
+        Some(cycle::floyd(3, square)),
+        cycle::find(std::iter::successors(Some(3), |x| Some(square(x))))
+    );
+    assert_eq!(None, cycle::find(0..10));
+
+    let cycle = Cycle {
+        start: 1,
+        length: 2,
+    };
+    assert_eq!(7, cycle.extrapolate(&[0, 5, 7, 10], 2));
+    assert_eq!(12, cycle.extrapolate(&[0, 5, 7, 10], 4));
+    assert_eq!(5 + 5 * 1000, cycle.extrapolate(&[0, 5, 7, 10], 2001));
+
+    assert_eq!(1, gcd_all([23, 19, 13, 17]));
+    assert_eq!(6, gcd_all([12, 18, 24]));
+    assert_eq!(Ok(96577), checked_lcm_all([23, 19, 13, 17]));
+    assert!(checked_lcm_all([u64::MAX, u64::MAX - 1]).is_err());
+    assert_eq!(Ok((2, -9, 47)), extended_gcd(240, 46));
+    assert_eq!(Ok((1, 0, 1)), extended_gcd(i64::MIN, 1));
+    assert!(extended_gcd(i64::MIN, 0).is_err());
+    assert_eq!(Some((23, 105)), crt([(2, 3), (3, 5), (2, 7)]));
+    // Non-coprime moduli
+    assert_eq!(Some((10, 12)), crt([(2, 4), (4, 6)]));
+    assert_eq!(None, crt([(1, 4), (2, 6)]));
+    // The combined modulus doesn't fit
+    assert_eq!(None, crt([(0, u64::MAX), (0, u64::MAX - 1)]));
+
+    assert_eq!(Some(Mod::new(4, 11)), Mod::new(3, 11).inverse());
+    assert_eq!(None, Mod::new(6, 9).inverse());
+    assert_eq!(1024, Mod::new(2, 1000000007).pow(10).value());
+    // Fermat's little theorem
+    assert_eq!(1, Mod::new(-3, 1000000007).pow(1000000006).value());
+
+    let value = json::parse(r#" {"a": [1, -2.5e3, true, null], "b": "x\"y"} "#).unwrap();
+    assert_eq!(
+        Some(1),
+        value.get("a").unwrap().as_array().unwrap()[0].as_i64()
+    );
+    assert_eq!(
+        Some(-2500),
+        value.get("a").unwrap().as_array().unwrap()[1].as_i64()
+    );
+    assert_eq!(Some("x\"y"), value.get("b").and_then(Value::as_str));
+    assert_eq!(
+        Value::Number("18446744073709551616".to_string()),
+        json::parse("18446744073709551616").unwrap()
+    );
+    assert_eq!(
+        Ok(Value::String("\u{1f600}\u{e9}".to_string())),
+        json::parse(r#""\uD83D\uDE00\u00e9""#)
+    );
+    // An unpaired surrogate can't be decoded
+    assert_eq!(
+        Ok(Value::String("\u{fffd}a".to_string())),
+        json::parse(r#""\uD83Da""#)
+    );
+    let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    assert!(json::parse(&nested(128)).is_ok());
+    assert!(json::parse(&nested(129)).is_err());
+    assert!(json::parse(&"[".repeat(100_000)).is_err());
+    let text = "tab\t \"quoted\" \\ \u{1}";
+    assert_eq!(
+        Ok(Value::String(text.to_string())),
+        json::parse(&json::string(text))
+    );
+
+    println!("Common checks passed");
+}
@@ -0,0 +1,246 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    hash::Hash,
+    mem::size_of,
+};
+
+// Rough per entry overhead of the hash maps, including their spare capacity
+const ENTRY_OVERHEAD: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Eviction {
+    /// Drops the least recently used entry when full
+    Lru,
+    /// Drops every entry not used since the previous time the cache was full,
+    /// much cheaper than tracking the recency of every entry
+    Generation,
+}
+
+/// Size bound and eviction policy of a memo cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    pub memory_limit: Option<usize>,
+    pub eviction: Eviction,
+    /// Whether the solver prints the statistics of its caches on stderr
+    pub report: bool,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            memory_limit: Some(512 * 1024 * 1024),
+            eviction: Eviction::Generation,
+            report: false,
+        }
+    }
+}
+
+impl CacheConfig {
+    pub fn unbounded() -> Self {
+        Self {
+            memory_limit: None,
+            ..Self::default()
+        }
+    }
+
+    /// Builds a configuration from the `--cache-limit <MiB>`,
+    /// `--cache-eviction <lru|generation>` and `--cache-stats` command line
+    /// arguments. A limit of zero means unbounded.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        let value_of = |name: &str| {
+            args.iter()
+                .position(|arg| arg == name)
+                .and_then(|index| args.get(index + 1))
+        };
+
+        let mut config = Self::default();
+
+        if let Some(limit) = value_of("--cache-limit") {
+            let megabytes = limit
+                .parse::<usize>()
+                .unwrap_or_else(|_| panic!("Invalid value for --cache-limit: {}", limit));
+            config.memory_limit = (megabytes != 0).then_some(megabytes * 1024 * 1024);
+        }
+
+        if let Some(eviction) = value_of("--cache-eviction") {
+            config.eviction = match eviction.as_str() {
+                "lru" => Eviction::Lru,
+                "generation" => Eviction::Generation,
+                e => panic!("Invalid value for --cache-eviction: {}", e),
+            };
+        }
+
+        config.report = args.iter().any(|arg| arg == "--cache-stats");
+
+        config
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub len: usize,
+}
+
+impl CacheStats {
+    pub fn hit_rate(&self) -> f64 {
+        if self.hits + self.misses == 0 {
+            return 0.0;
+        }
+
+        self.hits as f64 / (self.hits + self.misses) as f64
+    }
+}
+
+impl Display for CacheStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} evictions, {} entries",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.evictions,
+            self.len
+        )
+    }
+}
+
+enum Store<K, V> {
+    Unbounded(HashMap<K, V>),
+    Lru {
+        // Values with the tick of their last use
+        entries: HashMap<K, (V, u64)>,
+        recency: BTreeMap<u64, K>,
+        tick: u64,
+    },
+    Generation {
+        current: HashMap<K, V>,
+        previous: HashMap<K, V>,
+    },
+}
+
+/// Memoization cache of a recursive search, holding at most `capacity`
+/// entries.
+pub struct MemoCache<K, V> {
+    store: Store<K, V>,
+    capacity: usize,
+    stats: CacheStats,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> MemoCache<K, V> {
+    pub fn new(config: &CacheConfig) -> Self {
+        let capacity = match config.memory_limit {
+            Some(limit) => (limit / (size_of::<K>() + size_of::<V>() + ENTRY_OVERHEAD)).max(1),
+            None => usize::MAX,
+        };
+
+        let store = match (config.memory_limit, config.eviction) {
+            (None, _) => Store::Unbounded(HashMap::new()),
+            (Some(_), Eviction::Lru) => Store::Lru {
+                entries: HashMap::new(),
+                recency: BTreeMap::new(),
+                tick: 0,
+            },
+            (Some(_), Eviction::Generation) => Store::Generation {
+                current: HashMap::new(),
+                previous: HashMap::new(),
+            },
+        };
+
+        Self {
+            store,
+            capacity,
+            stats: CacheStats::default(),
+        }
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = match &mut self.store {
+            Store::Unbounded(entries) => entries.get(key).cloned(),
+            Store::Lru {
+                entries,
+                recency,
+                tick,
+            } => entries.get_mut(key).map(|(value, last_use)| {
+                *tick += 1;
+                let key = recency.remove(last_use).unwrap();
+                recency.insert(*tick, key);
+                *last_use = *tick;
+                value.clone()
+            }),
+            Store::Generation { current, previous } => match current.get(key) {
+                Some(value) => Some(value.clone()),
+                None => previous.remove(key).inspect(|value| {
+                    // Still in use, keep it for the next generation
+                    current.insert(key.clone(), value.clone());
+                }),
+            },
+        };
+
+        if value.is_some() {
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+        }
+
+        value
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        match &mut self.store {
+            Store::Unbounded(entries) => {
+                entries.insert(key, value);
+            }
+            Store::Lru {
+                entries,
+                recency,
+                tick,
+            } => {
+                *tick += 1;
+                if let Some((_, last_use)) = entries.insert(key.clone(), (value, *tick)) {
+                    recency.remove(&last_use);
+                } else if entries.len() > self.capacity {
+                    let (_, oldest) = recency.pop_first().unwrap();
+                    entries.remove(&oldest);
+                    self.stats.evictions += 1;
+                }
+                recency.insert(*tick, key);
+            }
+            Store::Generation { current, previous } => {
+                // Both generations together stay within the capacity
+                if current.len() >= self.capacity.div_ceil(2) {
+                    self.stats.evictions += previous.len() as u64;
+                    *previous = std::mem::take(current);
+                }
+                current.insert(key, value);
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.store {
+            Store::Unbounded(entries) => entries.len(),
+            Store::Lru { entries, .. } => entries.len(),
+            Store::Generation { current, previous } => current.len() + previous.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            len: self.len(),
+            ..self.stats
+        }
+    }
+}
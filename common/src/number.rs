@@ -0,0 +1,225 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::overflow::Overflow;
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+    a
+}
+
+/// Panics on overflow.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    checked_lcm(a, b).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn checked_lcm(a: u64, b: u64) -> Result<u64, Overflow> {
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+
+    (a / gcd(a, b))
+        .checked_mul(b)
+        .ok_or_else(|| Overflow::new(format!("lcm({}, {})", a, b)))
+}
+
+/// Greatest common divisor of all values, zero for no values.
+pub fn gcd_all(values: impl IntoIterator<Item = u64>) -> u64 {
+    values.into_iter().fold(0, gcd)
+}
+
+/// Least common multiple of all values, one for no values. Panics on
+/// overflow.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> u64 {
+    values.into_iter().fold(1, lcm)
+}
+
+pub fn checked_lcm_all(values: impl IntoIterator<Item = u64>) -> Result<u64, Overflow> {
+    values.into_iter().try_fold(1, checked_lcm)
+}
+
+// Coefficients stay within the magnitude of the inputs, the moduli of Mod
+// don't fit into i64 though
+fn extended_gcd_i128(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Returns `(g, x, y)` where `g` is the non-negative greatest common divisor
+/// of `a` and `b`, and `a * x + b * y == g`. Only `g` can overflow, when it is
+/// 2^63 for `i64::MIN` and zero or itself.
+pub fn extended_gcd(a: i64, b: i64) -> Result<(i64, i64, i64), Overflow> {
+    let (g, x, y) = extended_gcd_i128(a as i128, b as i128);
+    let g = i64::try_from(g).map_err(|_| Overflow::new(format!("extended_gcd({}, {})", a, b)))?;
+    Ok((g, x as i64, y as i64))
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` for every pair, the moduli
+/// need not be coprime. Returns the smallest non-negative solution with the
+/// modulus of the combined congruence, or `None` if the system has no
+/// solution or the combined modulus doesn't fit into u64.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, u64)>) -> Option<(u64, u64)> {
+    let mut res = Mod::from_u64(0, 1);
+
+    for (residue, modulus) in congruences {
+        let other = Mod::new(residue, modulus);
+
+        // res.value + res.modulus * k ≡ other.value (mod other.modulus)
+        let g = gcd(res.modulus, modulus);
+        let difference = other.value as i128 - res.value as i128;
+        if difference % g as i128 != 0 {
+            return None;
+        }
+
+        let reduced_modulus = modulus / g;
+        let inverse = Mod::from_u64((res.modulus / g) % reduced_modulus, reduced_modulus)
+            .inverse()
+            .unwrap();
+        let k = Mod::new_i128(difference / g as i128, reduced_modulus) * inverse;
+
+        let combined_modulus = checked_lcm(res.modulus, modulus).ok()?;
+        let value =
+            (res.value as u128 + res.modulus as u128 * k.value as u128) % combined_modulus as u128;
+        res = Mod::from_u64(value as u64, combined_modulus);
+    }
+
+    Some((res.value, res.modulus))
+}
+
+/// Residue class modulo `modulus`. Arithmetic is done in 128 bits so it can't
+/// overflow for any modulus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mod {
+    value: u64,
+    modulus: u64,
+}
+
+impl Mod {
+    /// Reduces `value` into `0..modulus`, negative values included.
+    pub fn new(value: i64, modulus: u64) -> Self {
+        Self::new_i128(value as i128, modulus)
+    }
+
+    fn new_i128(value: i128, modulus: u64) -> Self {
+        assert!(modulus > 0, "Modulus must be positive");
+
+        Self {
+            value: value.rem_euclid(modulus as i128) as u64,
+            modulus,
+        }
+    }
+
+    pub fn from_u64(value: u64, modulus: u64) -> Self {
+        assert!(modulus > 0, "Modulus must be positive");
+
+        Self {
+            value: value % modulus,
+            modulus,
+        }
+    }
+
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    pub fn modulus(self) -> u64 {
+        self.modulus
+    }
+
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let mut res = Self::from_u64(1, self.modulus);
+        let mut base = self;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                res = res * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+
+        res
+    }
+
+    /// Multiplicative inverse, if the value is coprime to the modulus.
+    pub fn inverse(self) -> Option<Self> {
+        let (g, x, _) = extended_gcd_i128(self.value as i128, self.modulus as i128);
+        (g == 1).then(|| Self::new_i128(x, self.modulus))
+    }
+
+    fn check_modulus(self, other: Self) {
+        assert_eq!(self.modulus, other.modulus, "Residues of different moduli");
+    }
+}
+
+impl Add for Mod {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.check_modulus(other);
+        Self::new_i128(self.value as i128 + other.value as i128, self.modulus)
+    }
+}
+
+impl Sub for Mod {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.check_modulus(other);
+        Self::new_i128(self.value as i128 - other.value as i128, self.modulus)
+    }
+}
+
+impl Mul for Mod {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.check_modulus(other);
+        let product = self.value as u128 * other.value as u128 % self.modulus as u128;
+        Self::from_u64(product as u64, self.modulus)
+    }
+}
+
+impl Neg for Mod {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new_i128(-(self.value as i128), self.modulus)
+    }
+}
+
+impl Add<i64> for Mod {
+    type Output = Self;
+
+    fn add(self, other: i64) -> Self {
+        self + Self::new(other, self.modulus)
+    }
+}
+
+impl Mul<u64> for Mod {
+    type Output = Self;
+
+    fn mul(self, other: u64) -> Self {
+        self * Self::from_u64(other, self.modulus)
+    }
+}
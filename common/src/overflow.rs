@@ -0,0 +1,111 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{
+    fmt::{Debug, Display},
+    num::{IntErrorKind, ParseIntError},
+    str::FromStr,
+};
+
+/// An arithmetic operation whose result doesn't fit into the integer type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow(String);
+
+impl Overflow {
+    pub fn new(operation: impl Into<String>) -> Self {
+        Self(operation.into())
+    }
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Overflow in {}", self.0)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Integer types solvers can be generic over, with arithmetic reporting
+/// overflow instead of wrapping or panicking.
+pub trait CheckedInt:
+    Copy + Ord + Debug + Display + FromStr<Err = ParseIntError> + 'static
+{
+    const ZERO: Self;
+    const NAME: &'static str;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+
+    fn try_add(self, other: Self) -> Result<Self, Overflow> {
+        self.checked_add(other)
+            .ok_or_else(|| Overflow::new(format!("{} + {} ({})", self, other, Self::NAME)))
+    }
+
+    fn try_sub(self, other: Self) -> Result<Self, Overflow> {
+        self.checked_sub(other)
+            .ok_or_else(|| Overflow::new(format!("{} - {} ({})", self, other, Self::NAME)))
+    }
+
+    fn try_mul(self, other: Self) -> Result<Self, Overflow> {
+        self.checked_mul(other)
+            .ok_or_else(|| Overflow::new(format!("{} * {} ({})", self, other, Self::NAME)))
+    }
+
+    /// Division by zero is reported as well.
+    fn try_div(self, other: Self) -> Result<Self, Overflow> {
+        self.checked_div(other)
+            .ok_or_else(|| Overflow::new(format!("{} / {} ({})", self, other, Self::NAME)))
+    }
+}
+
+macro_rules! impl_checked_int {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedInt for $t {
+                const ZERO: Self = 0;
+                const NAME: &'static str = stringify!($t);
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn checked_div(self, other: Self) -> Option<Self> {
+                    <$t>::checked_div(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_int!(u16, u32, u64, u128, usize, i16, i32, i64, i128);
+
+/// Parses an integer, returning `None` if the text isn't one and an error if
+/// it doesn't fit into the type.
+pub fn parse<T: CheckedInt>(text: &str) -> Option<Result<T, Overflow>> {
+    match text.parse() {
+        Ok(value) => Some(Ok(value)),
+        Err(e)
+            if matches!(
+                e.kind(),
+                IntErrorKind::PosOverflow | IntErrorKind::NegOverflow
+            ) =>
+        {
+            Some(Err(Overflow::new(format!(
+                "parsing {} ({})",
+                text,
+                T::NAME
+            ))))
+        }
+        Err(_) => None,
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{any::type_name, fmt::Display, str::FromStr};
+
+/// What the parser expected and where, with the text being parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub expected: String,
+    /// Character offset into the text
+    pub column: usize,
+    pub text: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rest: String = self.text.chars().skip(self.column).take(20).collect();
+        write!(
+            f,
+            "expected {} at column {} of {:?}, found {:?}",
+            self.expected,
+            self.column + 1,
+            self.text,
+            rest
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Cursor over a text with combinators for the usual puzzle input formats.
+/// Failed matches don't consume any input.
+pub struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            expected: expected.into(),
+            column: self.text[..self.position].chars().count(),
+            text: self.text.to_string(),
+        }
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.position == self.text.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.error("end of text"))
+        }
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.optional(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("{:?}", literal)))
+        }
+    }
+
+    /// Consumes `literal` if the text continues with it.
+    pub fn optional(&mut self, literal: &str) -> bool {
+        if self.rest().starts_with(literal) {
+            self.position += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes the longest non-empty prefix of characters matching
+    /// `predicate`.
+    pub fn take_while(
+        &mut self,
+        expected: &str,
+        predicate: impl Fn(char) -> bool,
+    ) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let length = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        if length == 0 {
+            return Err(self.error(expected));
+        }
+
+        self.position += length;
+        Ok(&rest[..length])
+    }
+
+    /// Alphanumeric token, like a name.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        self.take_while("a word", |c| c.is_alphanumeric())
+    }
+
+    pub fn one_of(&mut self, chars: &str) -> Result<char, ParseError> {
+        match self.peek() {
+            Some(c) if chars.contains(c) => {
+                self.position += c.len_utf8();
+                Ok(c)
+            }
+            _ => Err(self.error(format!("one of {:?}", chars))),
+        }
+    }
+
+    /// Decimal integer with an optional sign.
+    pub fn integer<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = self.position;
+        let _sign = self.one_of("+-");
+        if self.take_while("", |c| c.is_ascii_digit()).is_err() {
+            self.position = start;
+            return Err(self.error("an integer"));
+        }
+
+        match self.text[start..self.position].parse() {
+            Ok(value) => Ok(value),
+            Err(_) => {
+                // Digits that don't fit into the type
+                self.position = start;
+                Err(self.error(format!("an integer of type {}", type_name::<T>())))
+            }
+        }
+    }
+
+    /// One or more items separated by `separator`.
+    pub fn sep_by<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+
+        loop {
+            let start = self.position;
+            if !self.optional(separator) {
+                break;
+            }
+
+            match item(self) {
+                Ok(value) => items.push(value),
+                Err(e) => {
+                    self.position = start;
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Items repeated until the end of the text.
+    pub fn many<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = Vec::new();
+        while !self.is_at_end() {
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+}
+
+/// Parses the whole text, failing if anything is left over.
+pub fn parse_all<'a, T>(
+    text: &'a str,
+    f: impl FnOnce(&mut Parser<'a>) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let mut parser = Parser::new(text);
+    let value = f(&mut parser)?;
+    parser.end()?;
+
+    Ok(value)
+}
+
+/// Matches the text against a fixed pattern where each `{}` stands for a
+/// non-empty field, which ends where the literal following it starts.
+/// Returns the fields, e.g. `["3", "-4"]` for `"x=3, y=-4"` and
+/// `"x={}, y={}"`.
+pub fn fields<'a>(text: &'a str, pattern: &str) -> Result<Vec<&'a str>, ParseError> {
+    let mut parser = Parser::new(text);
+    let mut fields = Vec::new();
+
+    let mut literals = pattern.split("{}").peekable();
+    parser.literal(literals.next().unwrap())?;
+    while let Some(literal) = literals.next() {
+        let field = if literal.is_empty() && literals.peek().is_none() {
+            parser.take_while("a field", |_| true)?
+        } else if literal.is_empty() {
+            panic!("Adjacent fields in pattern {:?}", pattern);
+        } else {
+            let end = parser
+                .rest()
+                .find(literal)
+                .filter(|end| *end > 0)
+                .ok_or_else(|| parser.error(format!("a field followed by {:?}", literal)))?;
+            let field = &parser.rest()[..end];
+            parser.position += end;
+            field
+        };
+
+        fields.push(field);
+        parser.literal(literal)?;
+    }
+    parser.end()?;
+
+    Ok(fields)
+}
+
+/// Same as `fields`, with every field parsed as an integer.
+pub fn integer_fields<T: FromStr>(text: &str, pattern: &str) -> Result<Vec<T>, ParseError> {
+    fields(text, pattern)?
+        .into_iter()
+        .map(|field| parse_all(field, |parser| parser.integer()))
+        .collect()
+}
+
+/// All integers in the text in order, ignoring everything else.
+pub fn integers<T: FromStr>(text: &str) -> Vec<T> {
+    let mut parser = Parser::new(text);
+    let mut res = Vec::new();
+
+    while let Some(c) = parser.peek() {
+        match parser.integer() {
+            Ok(value) => res.push(value),
+            Err(_) => parser.position += c.len_utf8(),
+        }
+    }
+
+    res
+}
@@ -0,0 +1,146 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::path::{Path, PathBuf};
+
+/// Compares rendered text with the golden file `snapshots/<name>.txt` of the
+/// calling day crate.
+///
+/// On mismatch the new rendering is stored as `snapshots/<name>.txt.new`,
+/// which can be accepted by the runner's `accept_snapshots` binary. Setting
+/// `UPDATE_SNAPSHOTS=1` overwrites the golden files directly. With the
+/// `embed-inputs` feature the golden file is embedded as well.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:literal, $actual:expr) => {{
+        #[cfg(feature = "embed-inputs")]
+        $crate::snapshot::assert_golden(
+            $name,
+            include_str!(concat!("../snapshots/", $name, ".txt")),
+            &$actual,
+        );
+        #[cfg(not(feature = "embed-inputs"))]
+        $crate::snapshot::assert_file(env!("CARGO_MANIFEST_DIR"), $name, &$actual);
+    }};
+}
+
+const PENDING_EXTENSION: &str = "new";
+
+pub fn golden_path(manifest_dir: &str, name: &str) -> PathBuf {
+    Path::new(manifest_dir)
+        .join("snapshots")
+        .join(format!("{}.txt", name))
+}
+
+pub fn assert_golden(name: &str, expected: &str, actual: &str) {
+    if expected != actual {
+        panic!("Snapshot {} changed\n{}", name, diff(expected, actual));
+    }
+}
+
+pub fn assert_file(manifest_dir: &str, name: &str, actual: &str) {
+    let path = golden_path(manifest_dir, name);
+    let pending_path = path.with_extension(format!("txt.{}", PENDING_EXTENSION));
+
+    if std::env::var("UPDATE_SNAPSHOTS").is_ok_and(|v| v == "1") {
+        write(&path, actual);
+        let _ = std::fs::remove_file(&pending_path);
+        return;
+    }
+
+    match std::fs::read_to_string(&path) {
+        Ok(expected) if expected == actual => {
+            let _ = std::fs::remove_file(&pending_path);
+        }
+        Ok(expected) => {
+            write(&pending_path, actual);
+            panic!(
+                "Snapshot {} changed, new version stored in {}\n{}",
+                name,
+                pending_path.display(),
+                diff(&expected, actual)
+            );
+        }
+        Err(_) => {
+            write(&pending_path, actual);
+            panic!(
+                "Snapshot {} is missing, new version stored in {}",
+                name,
+                pending_path.display()
+            );
+        }
+    }
+}
+
+fn write(path: &Path, content: &str) {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .unwrap_or_else(|e| panic!("Failed to create {}: {}", dir.display(), e));
+    }
+    std::fs::write(path, content)
+        .unwrap_or_else(|e| panic!("Failed to write {}: {}", path.display(), e));
+}
+
+/// Replaces the golden files with the pending snapshots in `snapshot_dir`.
+/// Returns the accepted golden files.
+pub fn accept_pending(snapshot_dir: &Path) -> Vec<PathBuf> {
+    let mut accepted = Vec::new();
+
+    let entries = match std::fs::read_dir(snapshot_dir) {
+        Ok(entries) => entries,
+        Err(_) => return accepted,
+    };
+
+    for entry in entries.flatten() {
+        let pending_path = entry.path();
+        if pending_path
+            .extension()
+            .is_some_and(|e| e == PENDING_EXTENSION)
+        {
+            let path = pending_path.with_extension("");
+            std::fs::rename(&pending_path, &path)
+                .unwrap_or_else(|e| panic!("Failed to accept {}: {}", pending_path.display(), e));
+            accepted.push(path);
+        }
+    }
+
+    accepted.sort();
+    accepted
+}
+
+/// Line based diff of the two texts, based on their longest common
+/// subsequence.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let a: Vec<&str> = expected.lines().collect();
+    let b: Vec<&str> = actual.lines().collect();
+
+    // lengths[i][j] is the LCS length of a[i..] and b[j..]
+    let mut lengths = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lengths[i][j] = if a[i] == b[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut res = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            res.push_str(&format!("  {}\n", a[i]));
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lengths[i + 1][j] >= lengths[i][j + 1]) {
+            res.push_str(&format!("- {}\n", a[i]));
+            i += 1;
+        } else {
+            res.push_str(&format!("+ {}\n", b[j]));
+            j += 1;
+        }
+    }
+
+    res
+}
@@ -0,0 +1,219 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Write},
+    path::Path,
+};
+
+use common::{
+    json::{self, Value},
+    overflow,
+};
+
+use crate::{Inventory, ReadError};
+
+const CSV_HEADER: &str = "elf,calories";
+
+/// Inventory file formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Items on separate lines, elves separated by blank lines
+    Puzzle,
+    /// One `elf,calories` row per item, with an optional header
+    Csv,
+    /// Array of the item arrays of each elf
+    Json,
+}
+
+impl Format {
+    /// Format by file extension, the puzzle format for unknown ones.
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        match path.as_ref().extension().and_then(|e| e.to_str()) {
+            Some(e) if e.eq_ignore_ascii_case("csv") => Format::Csv,
+            Some(e) if e.eq_ignore_ascii_case("json") => Format::Json,
+            _ => Format::Puzzle,
+        }
+    }
+}
+
+/// CSV record: the line it starts on and its fields.
+type Record = (usize, Vec<String>);
+
+/// Records of RFC 4180 CSV text. Quoted fields may contain commas, new lines
+/// and quotes doubled as `""`, spaces around unquoted fields are trimmed.
+/// Returns the line of the first malformed record on error.
+fn csv_records(text: &str) -> Result<Vec<Record>, usize> {
+    let mut records = Vec::new();
+
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+    while chars.peek().is_some() {
+        let start = line;
+        let mut fields = Vec::new();
+        loop {
+            let mut field = String::new();
+            while chars.next_if(|&c| c == ' ' || c == '\t').is_some() {}
+
+            if chars.next_if_eq(&'"').is_some() {
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.next_if_eq(&'"').is_none() => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            field.push(c);
+                        }
+                        None => return Err(start),
+                    }
+                }
+                while chars.next_if(|c| c.is_whitespace() && *c != '\n').is_some() {}
+            } else {
+                while let Some(c) = chars.next_if(|&c| c != ',' && c != '\n') {
+                    if c == '"' {
+                        return Err(line);
+                    }
+                    field.push(c);
+                }
+                field = field.trim().to_string();
+            }
+            fields.push(field);
+
+            match chars.next() {
+                Some(',') => {}
+                Some('\n') => {
+                    line += 1;
+                    break;
+                }
+                None => break,
+                Some(_) => return Err(line),
+            }
+        }
+
+        records.push((start, fields));
+    }
+
+    Ok(records)
+}
+
+/// Elves may have any name, they are numbered in the order of their first
+/// row. A first row without a number of calories is the header.
+fn read_csv(mut reader: impl BufRead) -> Result<Vec<Vec<u64>>, ReadError> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+
+    let invalid = |line: usize| ReadError::InvalidLine {
+        line,
+        text: text.lines().nth(line - 1).unwrap_or_default().to_string(),
+    };
+    let records = csv_records(&text).map_err(invalid)?;
+
+    let mut items: Vec<Vec<u64>> = Vec::new();
+    let mut elves: HashMap<String, usize> = HashMap::new();
+    let rows = records.iter().filter(|(_, fields)| fields != &[""]);
+    for (index, (line, fields)) in rows.enumerate() {
+        let [elf, calories] = fields.as_slice() else {
+            return Err(invalid(*line));
+        };
+        let calories = match overflow::parse::<u64>(calories) {
+            Some(calories) => calories?,
+            None if index == 0 => continue,
+            None => return Err(invalid(*line)),
+        };
+        if elf.is_empty() {
+            return Err(invalid(*line));
+        }
+
+        let next = elves.len();
+        let elf = *elves.entry(elf.clone()).or_insert(next);
+        if elf == items.len() {
+            items.push(Vec::new());
+        }
+        items[elf].push(calories);
+    }
+
+    Ok(items)
+}
+
+fn read_json(mut reader: impl BufRead) -> Result<Vec<Vec<u64>>, ReadError> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+
+    let invalid = |expected: &str| ReadError::InvalidJson(format!("Expected {}", expected));
+    let calories = |item: &Value| -> Result<u64, ReadError> {
+        let Value::Number(number) = item else {
+            return Err(invalid("calories"));
+        };
+        // Calories too large are told apart from bad syntax
+        Ok(overflow::parse::<u64>(number).ok_or_else(|| invalid("whole calories"))??)
+    };
+
+    json::parse(&text)
+        .map_err(ReadError::InvalidJson)?
+        .as_array()
+        .ok_or_else(|| invalid("an array of elves"))?
+        .iter()
+        .map(|elf| {
+            elf.as_array()
+                .ok_or_else(|| invalid("an array of calories"))?
+                .iter()
+                .map(calories)
+                .collect()
+        })
+        .collect()
+}
+
+impl Inventory {
+    pub fn read_format(reader: impl BufRead, format: Format) -> Result<Self, ReadError> {
+        let items = match format {
+            Format::Puzzle => return Self::read(reader),
+            Format::Csv => read_csv(reader)?,
+            Format::Json => read_json(reader)?,
+        };
+
+        Ok(Self::new(items)?)
+    }
+
+    /// Elves without items are lost in the puzzle and CSV formats.
+    pub fn write(&self, mut writer: impl Write, format: Format) -> io::Result<()> {
+        match format {
+            Format::Puzzle => {
+                let groups: Vec<String> = self
+                    .items()
+                    .iter()
+                    .filter(|items| !items.is_empty())
+                    .map(|items| {
+                        items
+                            .iter()
+                            .map(|calories| calories.to_string())
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    })
+                    .collect();
+                writeln!(writer, "{}", groups.join("\n\n"))
+            }
+            Format::Csv => {
+                writeln!(writer, "{}", CSV_HEADER)?;
+                for (elf, items) in self.items().iter().enumerate() {
+                    for calories in items {
+                        writeln!(writer, "{},{}", elf, calories)?;
+                    }
+                }
+                Ok(())
+            }
+            Format::Json => {
+                let elves: Vec<String> = self
+                    .items()
+                    .iter()
+                    .map(|items| {
+                        let items: Vec<String> = items.iter().map(|c| c.to_string()).collect();
+                        format!("[{}]", items.join(","))
+                    })
+                    .collect();
+                writeln!(writer, "[{}]", elves.join(","))
+            }
+        }
+    }
+}
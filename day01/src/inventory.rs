@@ -0,0 +1,183 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::io::BufRead;
+
+use common::overflow::Overflow;
+
+use crate::{groups, total, Elf, ReadError};
+
+const HISTOGRAM_BINS: usize = 8;
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Elves with `start <= calories <= last`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bin {
+    pub start: u64,
+    pub last: u64,
+    pub count: usize,
+}
+
+/// Items carried by every elf.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    items: Vec<Vec<u64>>,
+    totals: Vec<u64>,
+}
+
+impl Inventory {
+    pub fn new(items: Vec<Vec<u64>>) -> Result<Self, Overflow> {
+        let totals = items
+            .iter()
+            .map(|items| total(items))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { items, totals })
+    }
+
+    /// Reads the puzzle format, see `read_format` for the others.
+    pub fn read(reader: impl BufRead) -> Result<Self, ReadError> {
+        let items = groups(reader).collect::<Result<Vec<Vec<u64>>, _>>()?;
+        Ok(Self::new(items)?)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Calories carried by each elf.
+    pub fn totals(&self) -> &[u64] {
+        &self.totals
+    }
+
+    /// Items carried by each elf.
+    pub fn items(&self) -> &[Vec<u64>] {
+        &self.items
+    }
+
+    /// Number of items carried by each elf.
+    pub fn item_counts(&self) -> Vec<usize> {
+        self.items.iter().map(|items| items.len()).collect()
+    }
+
+    fn sorted_totals(&self) -> Vec<u64> {
+        let mut totals = self.totals.clone();
+        totals.sort();
+        totals
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let sum: u128 = self.totals.iter().map(|&calories| calories as u128).sum();
+        Some(sum as f64 / self.len() as f64)
+    }
+
+    /// Average of the two middle totals for an even number of elves.
+    pub fn median(&self) -> Option<f64> {
+        let totals = self.sorted_totals();
+        let middle = totals.len() / 2;
+
+        match totals.len() {
+            0 => None,
+            len if len % 2 == 1 => Some(totals[middle] as f64),
+            _ => Some((totals[middle - 1] as f64 + totals[middle] as f64) / 2.0),
+        }
+    }
+
+    /// Smallest total that at least `percent` percent of the elves carry at
+    /// most (nearest rank method).
+    pub fn percentile(&self, percent: f64) -> Option<u64> {
+        if self.is_empty() || !(0.0..=100.0).contains(&percent) {
+            return None;
+        }
+
+        let totals = self.sorted_totals();
+        let rank = (percent / 100.0 * totals.len() as f64).ceil() as usize;
+        Some(totals[rank.max(1) - 1])
+    }
+
+    /// Totals divided into `bins` ranges of equal width between the smallest
+    /// and largest total.
+    pub fn histogram(&self, bins: usize) -> Vec<Bin> {
+        let (Some(&min), Some(&max)) = (self.totals.iter().min(), self.totals.iter().max()) else {
+            return Vec::new();
+        };
+        // Widened, the range of totals may not fit into u64. The bins start
+        // at most at the largest total, only the last one can end above it
+        let bins = bins.max(1) as u128;
+        let width = ((max - min) as u128 + 1).div_ceil(bins);
+
+        let mut res: Vec<Bin> = (0..bins)
+            .map(|bin| {
+                let start = min as u128 + bin * width;
+                Bin {
+                    start: start as u64,
+                    last: (start + width - 1).min(u64::MAX as u128) as u64,
+                    count: 0,
+                }
+            })
+            .collect();
+        for calories in &self.totals {
+            res[((calories - min) as u128 / width) as usize].count += 1;
+        }
+
+        res
+    }
+
+    /// Elves carrying more than `threshold` calories, in input order.
+    pub fn above(&self, threshold: u64) -> Vec<Elf<u64>> {
+        self.totals
+            .iter()
+            .enumerate()
+            .filter(|(_, &calories)| calories > threshold)
+            .map(|(index, &calories)| Elf { index, calories })
+            .collect()
+    }
+
+    pub fn report(&self) -> String {
+        let format = |value: Option<f64>| value.map_or("-".to_string(), |v| format!("{:.1}", v));
+        let item_counts = self.item_counts();
+
+        let mut res = String::new();
+        res += &format!("Elves: {}\n", self.len());
+        res += &format!("Items: {}\n", item_counts.iter().sum::<usize>());
+        res += &format!(
+            "Items per elf: {} - {}\n",
+            item_counts.iter().min().unwrap_or(&0),
+            item_counts.iter().max().unwrap_or(&0)
+        );
+        res += &format!("Mean calories: {}\n", format(self.mean()));
+        res += &format!("Median calories: {}\n", format(self.median()));
+        for percent in [25, 75, 90] {
+            res += &format!(
+                "{}th percentile: {}\n",
+                percent,
+                self.percentile(percent as f64)
+                    .map_or("-".to_string(), |p| p.to_string())
+            );
+        }
+
+        let histogram = self.histogram(HISTOGRAM_BINS);
+        let largest = histogram.iter().map(|bin| bin.count).max().unwrap_or(0);
+        res += "Histogram:\n";
+        for bin in histogram {
+            res += &format!(
+                "{:>8} - {:<8} {:<width$} {}\n",
+                bin.start,
+                bin.last,
+                "#".repeat(bin.count * HISTOGRAM_WIDTH / largest),
+                bin.count,
+                width = HISTOGRAM_WIDTH
+            );
+        }
+
+        res
+    }
+}
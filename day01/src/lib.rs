@@ -0,0 +1,184 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt::Display,
+    io::{self, BufRead},
+    marker::PhantomData,
+};
+
+use common::{
+    alloc,
+    overflow::{self, CheckedInt, Overflow},
+};
+
+pub use crate::{
+    format::Format,
+    inventory::{Bin, Inventory},
+    rebalance::{Plan, Snack},
+};
+
+mod format;
+mod inventory;
+pub mod rebalance;
+
+/// Calories carried by an elf, elves are indexed from 0 in input order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf<T> {
+    pub index: usize,
+    pub calories: T,
+}
+
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Overflow(Overflow),
+    /// Line not matching the input format, lines are numbered from 1
+    InvalidLine {
+        line: usize,
+        text: String,
+    },
+    InvalidJson(String),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "Failed to read input: {}", e),
+            ReadError::Overflow(e) => write!(f, "{}", e),
+            ReadError::InvalidLine { line, text } => {
+                write!(f, "Invalid line {}: {:?}", line, text)
+            }
+            ReadError::InvalidJson(e) => write!(f, "Invalid JSON inventory: {}", e),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+impl From<Overflow> for ReadError {
+    fn from(e: Overflow) -> Self {
+        ReadError::Overflow(e)
+    }
+}
+
+/// The `k` elves carrying the most calories seen so far. Of elves carrying
+/// the same amount the earlier one is kept.
+pub struct TopK<T> {
+    k: usize,
+    // Min-heap, the root is the first elf to drop
+    heap: BinaryHeap<Reverse<(T, Reverse<usize>)>>,
+}
+
+impl<T: CheckedInt> TopK<T> {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, elf: Elf<T>) {
+        self.heap.push(Reverse((elf.calories, Reverse(elf.index))));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// Elves in decreasing order of calories.
+    pub fn into_sorted_vec(self) -> Vec<Elf<T>> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| Elf { index, calories })
+            .collect()
+    }
+}
+
+/// Items of the elves read group by group. Groups are separated by any
+/// number of blank lines, the final new line is optional.
+pub struct Groups<R, T> {
+    lines: io::Lines<R>,
+    line: usize,
+    marker: PhantomData<T>,
+}
+
+pub fn groups<R: BufRead, T: CheckedInt>(reader: R) -> Groups<R, T> {
+    Groups {
+        lines: reader.lines(),
+        line: 0,
+        marker: PhantomData,
+    }
+}
+
+impl<R: BufRead, T: CheckedInt> Iterator for Groups<R, T> {
+    type Item = Result<Vec<T>, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut items = Vec::new();
+        for line in self.lines.by_ref() {
+            self.line += 1;
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+
+            if line.trim().is_empty() {
+                if items.is_empty() {
+                    continue;
+                }
+                return Some(Ok(items));
+            }
+
+            match overflow::parse::<T>(line.trim_end_matches('\r')) {
+                Some(Ok(item)) => items.push(item),
+                Some(Err(e)) => return Some(Err(e.into())),
+                None => {
+                    return Some(Err(ReadError::InvalidLine {
+                        line: self.line,
+                        text: line,
+                    }))
+                }
+            }
+        }
+
+        (!items.is_empty()).then_some(Ok(items))
+    }
+}
+
+fn total<T: CheckedInt>(items: &[T]) -> Result<T, Overflow> {
+    items
+        .iter()
+        .try_fold(T::ZERO, |sum, item| sum.try_add(*item))
+}
+
+/// Reads the elf groups line by line, keeping only the `k` largest totals.
+pub fn top_elves<T: CheckedInt>(reader: impl BufRead, k: usize) -> Result<Vec<Elf<T>>, ReadError> {
+    let mut top = TopK::new(k);
+
+    for (index, items) in groups::<_, T>(reader).enumerate() {
+        top.push(Elf {
+            index,
+            calories: total(&items?)?,
+        });
+    }
+
+    Ok(top.into_sorted_vec())
+}
+
+pub fn part12<T: CheckedInt>(input: &str) -> Result<(T, T), ReadError> {
+    // Reading keeps only the top elves, the rest of the work is summing them
+    let elves = alloc::phase("parse", || top_elves::<T>(input.as_bytes(), 3))?;
+
+    // Without elves nobody carries anything
+    Ok((
+        elves.first().map_or(T::ZERO, |elf| elf.calories),
+        total(&elves.iter().map(|elf| elf.calories).collect::<Vec<_>>())?,
+    ))
+}
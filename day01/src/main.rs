@@ -1,41 +1,204 @@
 // Copyright (c) 2022, Kis Imre. All rights reserved.
 // SPDX-License-Identifier: MIT
 
-fn part12(input: &str) -> (u32, u32) {
-    let mut calories_per_elf: Vec<Option<u32>> = input
-        .split("\n")
-        .map(|line| line.parse::<u32>().ok())
-        .collect();
-    calories_per_elf.dedup_by(|a, b| {
-        if let (Some(a), Some(b)) = (a, b) {
-            *b += *a;
-            true
-        } else {
-            false
-        }
-    });
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+};
 
-    let mut sum_calories_per_elf: Vec<u32> = calories_per_elf
-        .iter()
-        .filter(|v| v.is_some())
-        .map(|v| v.unwrap())
-        .collect();
+use common::input;
+use day01::{part12, rebalance, top_elves, Bin, Elf, Format, Inventory, ReadError};
 
-    sum_calories_per_elf.sort();
-    sum_calories_per_elf.reverse();
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let value_of = |name: &str| {
+        args.iter()
+            .position(|arg| arg == name)
+            .and_then(|index| args.get(index + 1))
+    };
 
-    (
-        sum_calories_per_elf[0],
-        sum_calories_per_elf.iter().take(3).sum::<u32>(),
-    )
-}
+    assert_eq!(
+        (24000, 45000),
+        part12::<u32>(&input!("input_test")).unwrap()
+    );
+    assert!(part12::<i16>(&input!("input_test")).is_err());
+    assert_eq!((0, 0), part12::<u32>("").unwrap());
+    assert_eq!((0, 0), part12::<u32>("\n\n").unwrap());
+    assert_eq!(
+        vec![
+            Elf {
+                index: 3,
+                calories: 24000
+            },
+            Elf {
+                index: 2,
+                calories: 11000
+            }
+        ],
+        top_elves::<u32>(input!("input_test").as_bytes(), 2).unwrap()
+    );
 
-fn main() {
-    assert_eq!((24000, 45000), part12(include_str!("input_test")));
+    assert_eq!(
+        vec![3000, 3000],
+        Inventory::read("1000\r\n2000\r\n\r\n\n \n3000\n\n".as_bytes())
+            .unwrap()
+            .totals()
+    );
+    assert!(matches!(
+        Inventory::read("1000\n2O00\n\n3000".as_bytes()),
+        Err(ReadError::InvalidLine { line: 2, text }) if text == "2O00"
+    ));
+
+    let inventory = Inventory::read(input!("input_test").as_bytes()).unwrap();
+    assert_eq!(vec![3, 1, 2, 3, 1], inventory.item_counts());
+    assert_eq!(Some(11000.0), inventory.mean());
+    assert_eq!(Some(10000.0), inventory.median());
+    assert_eq!(Some(6000), inventory.percentile(25.0));
+    assert_eq!(Some(24000), inventory.percentile(100.0));
+    assert_eq!(
+        vec![
+            Bin {
+                start: 4000,
+                last: 14000,
+                count: 4
+            },
+            Bin {
+                start: 14001,
+                last: 24001,
+                count: 1
+            }
+        ],
+        inventory.histogram(2)
+    );
+    assert_eq!(
+        vec![
+            Bin {
+                start: 0,
+                last: u64::MAX / 2,
+                count: 1
+            },
+            Bin {
+                start: u64::MAX / 2 + 1,
+                last: u64::MAX,
+                count: 1
+            }
+        ],
+        Inventory::read("0\n\n18446744073709551615".as_bytes())
+            .unwrap()
+            .histogram(2)
+    );
+    assert_eq!(
+        vec![2, 3],
+        inventory
+            .above(10000)
+            .iter()
+            .map(|elf| elf.index)
+            .collect::<Vec<_>>()
+    );
 
-    let (part1, part2) = part12(include_str!("input"));
+    assert_eq!(19000, rebalance::plan(&inventory, 3).max_load());
+    assert_eq!(28000, rebalance::plan(&inventory, 2).max_load());
+    let snacks = rebalance::snacks(&Inventory::read("3\n3\n2\n2\n2".as_bytes()).unwrap());
+    assert_eq!(7, rebalance::greedy(&snacks, 2).max_load());
+    assert_eq!(6, rebalance::exact(&snacks, 2).max_load());
+
+    for format in [Format::Puzzle, Format::Csv, Format::Json] {
+        let mut exported = Vec::new();
+        inventory.write(&mut exported, format).unwrap();
+        assert_eq!(
+            inventory,
+            Inventory::read_format(exported.as_slice(), format).unwrap()
+        );
+    }
+    assert_eq!(
+        &[vec![1000, 500], vec![2000]],
+        Inventory::read_format(
+            "elf,calories\nAlice,1000\nBob,2000\nAlice,500\n".as_bytes(),
+            Format::Csv
+        )
+        .unwrap()
+        .items()
+    );
+    assert_eq!(
+        &[vec![1000, 2000], vec![], vec![3000]],
+        Inventory::read_format(" [[1000, 2000], [], [3000]]\n".as_bytes(), Format::Json)
+            .unwrap()
+            .items()
+    );
+    assert!(matches!(
+        Inventory::read_format("Alice,1000\nBob\n".as_bytes(), Format::Csv),
+        Err(ReadError::InvalidLine { line: 2, .. })
+    ));
+    assert_eq!(
+        &[vec![1000, 500], vec![2000], vec![5]],
+        Inventory::read_format(
+            "Name, Calories\r\n\"Smith, Alice\",1000\r\nBob ,2000\r\n\"Smith, Alice\", 500\r\n\"Say \"\"hi\"\"\nto Bob\",5"
+                .as_bytes(),
+            Format::Csv
+        )
+        .unwrap()
+        .items()
+    );
+    assert!(matches!(
+        Inventory::read_format("Alice,1000\n\"Bob,2000\n".as_bytes(), Format::Csv),
+        Err(ReadError::InvalidLine { line: 2, .. })
+    ));
+    assert!(matches!(
+        Inventory::read_format("Alice,1000\nB\"ob,2000\n".as_bytes(), Format::Csv),
+        Err(ReadError::InvalidLine { line: 2, .. })
+    ));
+    assert!(matches!(
+        Inventory::read_format("[[1000,]]".as_bytes(), Format::Json),
+        Err(ReadError::InvalidJson(_))
+    ));
+    assert!(matches!(
+        Inventory::read_format("[[1000, 18446744073709551616]]".as_bytes(), Format::Json),
+        Err(ReadError::Overflow(_))
+    ));
+    assert_eq!(Format::Csv, Format::from_path("elves.CSV"));
+    assert_eq!(Format::Json, Format::from_path("data/elves.json"));
+    assert_eq!(Format::Puzzle, Format::from_path("input"));
+
+    let (part1, part2) = part12::<u64>(&input!("input")).unwrap();
 
     println!("Day 01");
     println!("Part 1: {}", part1);
     println!("Part 2: {}", part2);
+    // Any inventory file can be analyzed, rebalanced and converted, e.g.
+    // `--inventory elves.csv --elves 4 --export elves.json`
+    let inventory = match value_of("--inventory") {
+        Some(path) => {
+            let file = File::open(path)
+                .unwrap_or_else(|e| panic!("Failed to open inventory {}: {}", path, e));
+            Inventory::read_format(BufReader::new(file), Format::from_path(path))
+                .unwrap_or_else(|e| panic!("Failed to read inventory {}: {}", path, e))
+        }
+        None => Inventory::read(input!("input").as_bytes()).unwrap(),
+    };
+    print!("{}", inventory.report());
+
+    let elves: usize = value_of("--elves")
+        .map(|elves| {
+            elves
+                .parse()
+                .ok()
+                .filter(|&elves| elves > 0)
+                .expect("Invalid elf count")
+        })
+        .unwrap_or(3);
+    let plan = rebalance::plan(&inventory, elves);
+    println!(
+        "Heaviest load over {} elves after rebalancing: {}",
+        elves,
+        plan.max_load()
+    );
+    print!("{}", plan);
+
+    if let Some(path) = value_of("--export") {
+        let file = File::create(path)
+            .unwrap_or_else(|e| panic!("Failed to create export {}: {}", path, e));
+        inventory
+            .write(BufWriter::new(file), Format::from_path(path))
+            .unwrap_or_else(|e| panic!("Failed to export inventory to {}: {}", path, e));
+    }
 }
@@ -0,0 +1,186 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{cmp::Reverse, collections::BinaryHeap, fmt::Display};
+
+use crate::Inventory;
+
+/// Above this many snacks the exact search is too slow.
+pub const EXACT_LIMIT: usize = 24;
+
+/// A snack item, identified by the elf originally carrying it and its
+/// position in that elf's list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snack {
+    pub elf: usize,
+    pub item: usize,
+    pub calories: u64,
+}
+
+/// Snacks assigned to each elf.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub elves: Vec<Vec<Snack>>,
+}
+
+impl Plan {
+    fn new(snacks: &[Snack], assignment: &[usize], elves: usize) -> Self {
+        let mut res = vec![Vec::new(); elves];
+        for (snack, &elf) in snacks.iter().zip(assignment) {
+            res[elf].push(*snack);
+        }
+
+        Self { elves: res }
+    }
+
+    /// Calories carried by each elf.
+    pub fn loads(&self) -> Vec<u64> {
+        self.elves
+            .iter()
+            .map(|snacks| snacks.iter().map(|snack| snack.calories).sum())
+            .collect()
+    }
+
+    /// Load of the most loaded elf.
+    pub fn max_load(&self) -> u64 {
+        self.loads().into_iter().max().unwrap_or(0)
+    }
+}
+
+impl Display for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (elf, (snacks, load)) in self.elves.iter().zip(self.loads()).enumerate() {
+            write!(f, "Elf {}: {} calories <-", elf, load)?;
+            for snack in snacks {
+                write!(
+                    f,
+                    " {} (elf {} item {})",
+                    snack.calories, snack.elf, snack.item
+                )?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Snacks of the inventory, the largest ones first.
+pub fn snacks(inventory: &Inventory) -> Vec<Snack> {
+    let mut res: Vec<Snack> = inventory
+        .items()
+        .iter()
+        .enumerate()
+        .flat_map(|(elf, items)| {
+            items
+                .iter()
+                .enumerate()
+                .map(move |(item, &calories)| Snack {
+                    elf,
+                    item,
+                    calories,
+                })
+        })
+        .collect();
+    res.sort_by_key(|snack| Reverse(snack.calories));
+
+    res
+}
+
+/// Longest processing time first: every snack goes to the least loaded elf,
+/// the largest snacks first. At most 4/3 times the optimal maximum load.
+pub fn greedy(snacks: &[Snack], elves: usize) -> Plan {
+    assert!(elves > 0, "Snacks can't be shared by zero elves");
+
+    let mut loads: BinaryHeap<Reverse<(u64, usize)>> =
+        (0..elves).map(|elf| Reverse((0, elf))).collect();
+    let mut assignment = Vec::with_capacity(snacks.len());
+    for snack in snacks {
+        let Reverse((load, elf)) = loads.pop().unwrap();
+        assignment.push(elf);
+        loads.push(Reverse((load + snack.calories, elf)));
+    }
+
+    Plan::new(snacks, &assignment, elves)
+}
+
+struct Search<'a> {
+    snacks: &'a [Snack],
+    lower_bound: u64,
+    loads: Vec<u64>,
+    assignment: Vec<usize>,
+    best_load: u64,
+    best_assignment: Vec<usize>,
+}
+
+impl Search<'_> {
+    // Returns true once a plan reaching the lower bound is found
+    fn search(&mut self, index: usize) -> bool {
+        if index == self.snacks.len() {
+            self.best_load = self.loads.iter().copied().max().unwrap_or(0);
+            self.best_assignment = self.assignment.clone();
+            return self.best_load == self.lower_bound;
+        }
+
+        let calories = self.snacks[index].calories;
+        for elf in 0..self.loads.len() {
+            // Elves with the same load are interchangeable
+            if self.loads[..elf].contains(&self.loads[elf])
+                || self.loads[elf] + calories >= self.best_load
+            {
+                continue;
+            }
+
+            self.loads[elf] += calories;
+            self.assignment[index] = elf;
+            let done = self.search(index + 1);
+            self.loads[elf] -= calories;
+            if done {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+/// Branch and bound over all assignments, starting from the greedy plan.
+/// The snacks must be sorted by decreasing calories.
+pub fn exact(snacks: &[Snack], elves: usize) -> Plan {
+    let greedy = greedy(snacks, elves);
+
+    let total: u64 = snacks.iter().map(|snack| snack.calories).sum();
+    let largest = snacks.first().map_or(0, |snack| snack.calories);
+    let lower_bound = total.div_ceil(elves as u64).max(largest);
+    if greedy.max_load() == lower_bound {
+        return greedy;
+    }
+
+    let mut search = Search {
+        snacks,
+        lower_bound,
+        loads: vec![0; elves],
+        assignment: vec![0; snacks.len()],
+        best_load: greedy.max_load(),
+        best_assignment: Vec::new(),
+    };
+    search.search(0);
+
+    if search.best_assignment.is_empty() {
+        greedy
+    } else {
+        Plan::new(snacks, &search.best_assignment, elves)
+    }
+}
+
+/// Redistributes the snacks of the inventory across `elves` elves minimizing
+/// the heaviest load, exactly when there are at most `EXACT_LIMIT` snacks.
+pub fn plan(inventory: &Inventory, elves: usize) -> Plan {
+    let snacks = snacks(inventory);
+
+    if snacks.len() <= EXACT_LIMIT {
+        exact(&snacks, elves)
+    } else {
+        greedy(&snacks, elves)
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::json;
+
+use crate::{parse_rounds, Decoding, Outcome, Rules};
+
+/// Score of a round of the strategy guide, rounds are numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundScore {
+    pub round: usize,
+    pub opponent: usize,
+    pub ours: usize,
+    pub outcome: Outcome,
+    pub shape_points: u32,
+    pub outcome_points: u32,
+    /// Running total including this round
+    pub total: u32,
+}
+
+pub fn breakdown(input: &str, rules: &Rules, decoding: &Decoding) -> Vec<RoundScore> {
+    let mut total = 0;
+
+    parse_rounds(input)
+        .iter()
+        .enumerate()
+        .map(|(index, round)| {
+            let (opponent, ours) = rules
+                .opponent_move(round.opponent)
+                .and_then(|theirs| {
+                    Some((theirs, decoding.our_move(rules, theirs, round.response)?))
+                })
+                .unwrap_or_else(|| panic!("Invalid input {:?}", round));
+            let outcome = rules.outcome(ours, opponent);
+            let (shape_points, outcome_points) =
+                (rules.shape_score(ours), rules.outcome_score(outcome));
+            total += shape_points + outcome_points;
+
+            RoundScore {
+                round: index + 1,
+                opponent,
+                ours,
+                outcome,
+                shape_points,
+                outcome_points,
+                total,
+            }
+        })
+        .collect()
+}
+
+pub fn to_table(rows: &[RoundScore], rules: &Rules) -> String {
+    let mut res = format!(
+        "{:>5}  {:<10}{:<10}{:<8}{:>10}{:>12}{:>8}\n",
+        "Round", "Opponent", "Ours", "Outcome", "Shape pts", "Outcome pts", "Total"
+    );
+    for row in rows {
+        res += &format!(
+            "{:>5}  {:<10}{:<10}{:<8}{:>10}{:>12}{:>8}\n",
+            row.round,
+            rules.move_name(row.opponent),
+            rules.move_name(row.ours),
+            format!("{:?}", row.outcome),
+            row.shape_points,
+            row.outcome_points,
+            row.total
+        );
+    }
+
+    res
+}
+
+pub fn to_json(rows: &[RoundScore], rules: &Rules) -> String {
+    let rows: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            format!(
+                "{{\"round\":{},\"opponent\":{},\"ours\":{},\"outcome\":{},\"shape_points\":{},\"outcome_points\":{},\"total\":{}}}",
+                row.round,
+                json::string(rules.move_name(row.opponent)),
+                json::string(rules.move_name(row.ours)),
+                json::string(&format!("{:?}", row.outcome).to_lowercase()),
+                row.shape_points,
+                row.outcome_points,
+                row.total
+            )
+        })
+        .collect();
+
+    format!("[{}]", rows.join(","))
+}
@@ -0,0 +1,160 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::cmp::Reverse;
+
+use crate::{
+    parse_rounds,
+    rules::{Outcome, OUTCOME_SYMBOLS},
+    Rules,
+};
+
+/// A reading of the response column of the strategy guide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decoding {
+    /// The n-th response symbol is the move at the n-th index, see
+    /// `Decoding::moves`
+    Moves(Vec<usize>),
+    /// The response symbols are the desired outcomes
+    Outcomes,
+}
+
+impl Decoding {
+    /// The part 1 reading, the response symbols are the moves in order.
+    pub fn part1(rules: &Rules) -> Self {
+        Decoding::Moves((0..rules.move_count()).collect())
+    }
+
+    /// Mapping of the response symbols to `moves`, which must be a
+    /// permutation of the moves of the rules.
+    pub fn moves(rules: &Rules, moves: Vec<usize>) -> Option<Self> {
+        let mut sorted = moves.clone();
+        sorted.sort_unstable();
+        sorted
+            .into_iter()
+            .eq(0..rules.move_count())
+            .then_some(Decoding::Moves(moves))
+    }
+
+    /// Our move for a response symbol against the opponent's move.
+    pub fn our_move(&self, rules: &Rules, theirs: usize, symbol: char) -> Option<usize> {
+        match self {
+            Decoding::Moves(moves) => rules
+                .response_move(symbol)
+                .and_then(|index| moves.get(index).copied()),
+            Decoding::Outcomes => rules.response(theirs, Rules::desired_outcome(symbol)?),
+        }
+    }
+
+    pub fn describe(&self, rules: &Rules) -> String {
+        let meanings: Vec<String> = match self {
+            Decoding::Moves(moves) => rules
+                .response_symbols()
+                .iter()
+                .zip(moves)
+                .map(|(symbol, &m)| format!("{}={}", symbol, rules.move_name(m)))
+                .collect(),
+            Decoding::Outcomes => OUTCOME_SYMBOLS
+                .iter()
+                .zip(Outcome::ALL)
+                .map(|(symbol, outcome)| format!("{}={:?}", symbol, outcome))
+                .collect(),
+        };
+
+        meanings.join(" ")
+    }
+}
+
+/// Total score of the strategy guide with a decoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub decoding: Decoding,
+    pub score: u32,
+}
+
+/// All orderings of `0..n` in lexicographic order.
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    let mut res = Vec::new();
+
+    let mut permutation: Vec<usize> = (0..n).collect();
+    loop {
+        res.push(permutation.clone());
+
+        // Next permutation: swap the last ascent with the next larger element
+        // after it, then reverse the tail
+        let Some(i) = (1..n).rev().find(|&i| permutation[i - 1] < permutation[i]) else {
+            return res;
+        };
+        let j = (i..n)
+            .rev()
+            .find(|&j| permutation[j] > permutation[i - 1])
+            .unwrap();
+        permutation.swap(i - 1, j);
+        permutation[i..].reverse();
+    }
+}
+
+/// Scores the guide with every mapping of the response symbols to moves and
+/// with the desired outcome reading, the most favourable first.
+pub fn analyze(input: &str, rules: &Rules) -> Vec<Analysis> {
+    let n = rules.move_count();
+
+    // Occurrences of each (opponent move, response symbol) pair
+    let mut counts = vec![vec![0; n]; n];
+    for round in parse_rounds(input) {
+        match (
+            rules.opponent_move(round.opponent),
+            rules.response_move(round.response),
+        ) {
+            (Some(theirs), Some(symbol)) => counts[theirs][symbol] += 1,
+            _ => panic!("Invalid input {:?}", round),
+        }
+    }
+
+    let score = |ours: &dyn Fn(usize, usize) -> Option<usize>| -> Option<u32> {
+        let mut res = 0;
+        for (theirs, symbols) in counts.iter().enumerate() {
+            for (symbol, &count) in symbols.iter().enumerate() {
+                if count > 0 {
+                    res += count * rules.score(ours(theirs, symbol)?, theirs);
+                }
+            }
+        }
+        Some(res)
+    };
+
+    let mut res: Vec<Analysis> = permutations(n)
+        .into_iter()
+        .map(|moves| Analysis {
+            score: score(&|_, symbol| Some(moves[symbol])).unwrap(),
+            decoding: Decoding::Moves(moves),
+        })
+        .collect();
+
+    // Only the symbols of the three outcomes are readable as outcomes
+    if let Some(score) = score(&|theirs, symbol| {
+        let outcome = Rules::desired_outcome(rules.response_symbols()[symbol])?;
+        rules.response(theirs, outcome)
+    }) {
+        res.push(Analysis {
+            decoding: Decoding::Outcomes,
+            score,
+        });
+    }
+
+    res.sort_by_key(|analysis| Reverse(analysis.score));
+    res
+}
+
+pub fn report(analyses: &[Analysis], rules: &Rules) -> String {
+    analyses
+        .iter()
+        .map(|analysis| {
+            format!(
+                "{:>8}  {}\n",
+                analysis.score,
+                analysis.decoding.describe(rules)
+            )
+        })
+        .collect()
+}
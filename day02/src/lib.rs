@@ -0,0 +1,101 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::alloc;
+
+pub use crate::{
+    breakdown::RoundScore,
+    cipher::{Analysis, Decoding},
+    rules::{Outcome, Rules},
+    synthesis::{NoPlan, Objective, Plan},
+    tournament::{Standing, Strategy},
+};
+
+pub mod breakdown;
+pub mod cipher;
+pub mod rules;
+pub mod synthesis;
+pub mod tournament;
+
+/// A line of the strategy guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: char,
+    pub response: char,
+}
+
+/// Every non-empty line must hold exactly two single character symbols.
+pub fn parse_rounds(input: &str) -> Vec<Round> {
+    input
+        .split("\n")
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| {
+            let symbols: Vec<&str> = line.split_whitespace().collect();
+            let symbol = |symbol: &str| {
+                let mut chars = symbol.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Some(c),
+                    _ => None,
+                }
+            };
+            match symbols[..] {
+                [opponent, response] => match (symbol(opponent), symbol(response)) {
+                    (Some(opponent), Some(response)) => Round { opponent, response },
+                    _ => panic!("Invalid input on line {}: {:?}", index + 1, line),
+                },
+                _ => panic!("Invalid input on line {}: {:?}", index + 1, line),
+            }
+        })
+        .collect()
+}
+
+/// Moves of the opponent column.
+pub fn opponent_moves(input: &str, rules: &Rules) -> Vec<usize> {
+    parse_rounds(input)
+        .iter()
+        .map(|round| {
+            rules
+                .opponent_move(round.opponent)
+                .unwrap_or_else(|| panic!("Invalid input {:?}", round))
+        })
+        .collect()
+}
+
+/// The response column is our move.
+pub fn get_score_part1(rules: &Rules, round: &Round) -> u32 {
+    get_score(rules, round, &Decoding::part1(rules))
+}
+
+/// The response column is the outcome we need.
+pub fn get_score_part2(rules: &Rules, round: &Round) -> u32 {
+    get_score(rules, round, &Decoding::Outcomes)
+}
+
+pub fn get_score(rules: &Rules, round: &Round, decoding: &Decoding) -> u32 {
+    rules
+        .opponent_move(round.opponent)
+        .and_then(|theirs| {
+            Some(rules.score(decoding.our_move(rules, theirs, round.response)?, theirs))
+        })
+        .unwrap_or_else(|| panic!("Invalid input {:?}", round))
+}
+
+pub fn score_guide(input: &str, rules: &Rules) -> (u32, u32) {
+    let rounds = alloc::phase("parse", || parse_rounds(input));
+
+    (
+        rounds
+            .iter()
+            .map(|round| get_score_part1(rules, round))
+            .sum(),
+        rounds
+            .iter()
+            .map(|round| get_score_part2(rules, round))
+            .sum(),
+    )
+}
+
+pub fn part12(input: &str) -> (u32, u32) {
+    score_guide(input, &Rules::rock_paper_scissors())
+}
@@ -1,59 +1,190 @@
 // Copyright (c) 2022, Kis Imre. All rights reserved.
 // SPDX-License-Identifier: MIT
 
-fn get_score_part1(line: &str) -> u32 {
-    let splitted: Vec<&str> = line.split_whitespace().collect();
-    match (splitted[0], splitted[1]) {
-        ("A", "X") => 1 + 3,
-        ("A", "Y") => 2 + 6,
-        ("A", "Z") => 3 + 0,
-        ("B", "X") => 1 + 0,
-        ("B", "Y") => 2 + 3,
-        ("B", "Z") => 3 + 6,
-        ("C", "X") => 1 + 6,
-        ("C", "Y") => 2 + 0,
-        ("C", "Z") => 3 + 3,
-        _ => panic!("Invalid input {:?}", splitted),
-    }
-}
+use common::input;
+use day02::{
+    breakdown, cipher, opponent_moves, parse_rounds, part12, score_guide, synthesis,
+    tournament::{self, Copycat, Cycle, FrequencyCounter, SeededRandom},
+    Analysis, Decoding, Objective, Round, Rules, Strategy,
+};
 
-fn get_score_part2(line: &str) -> u32 {
-    let splitted: Vec<&str> = line.split_whitespace().collect();
-    match (splitted[0], splitted[1]) {
-        ("A", "X") => 0 + 3,
-        ("A", "Y") => 3 + 1,
-        ("A", "Z") => 6 + 2,
-        ("B", "X") => 0 + 1,
-        ("B", "Y") => 3 + 2,
-        ("B", "Z") => 6 + 3,
-        ("C", "X") => 0 + 2,
-        ("C", "Y") => 3 + 3,
-        ("C", "Z") => 6 + 1,
-        _ => panic!("Invalid input {:?}", splitted),
-    }
-}
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
 
-fn part12(input: &str) -> (u32, u32) {
-    (
-        input
-            .split("\n")
-            .filter(|line| !line.is_empty())
-            .map(get_score_part1)
-            .sum(),
-        input
-            .split("\n")
-            .filter(|line| !line.is_empty())
-            .map(get_score_part2)
-            .sum(),
-    )
-}
+    assert_eq!((15, 12), part12(&input!("input_test")));
+    assert_eq!(
+        vec![
+            Round {
+                opponent: 'A',
+                response: 'Y'
+            },
+            Round {
+                opponent: 'C',
+                response: 'Z'
+            }
+        ],
+        parse_rounds("A Y\r\n\nC \tZ\n")
+    );
+    assert_eq!(
+        (24, 12),
+        score_guide(
+            &input!("input_test"),
+            &Rules::rock_paper_scissors_lizard_spock()
+        )
+    );
 
-fn main() {
-    assert_eq!((15, 12), part12(include_str!("input_test")));
+    let rules = Rules::rock_paper_scissors();
+    let analyses = cipher::analyze(&input!("input_test"), &rules);
+    assert_eq!(7, analyses.len());
+    assert_eq!(
+        Analysis {
+            decoding: Decoding::Moves(vec![2, 1, 0]),
+            score: 24
+        },
+        analyses[0]
+    );
+    assert_eq!(
+        Some(Decoding::Moves(vec![2, 0, 1])),
+        Decoding::moves(&rules, vec![2, 0, 1])
+    );
+    assert_eq!(None, Decoding::moves(&rules, vec![1, 0]));
+    assert_eq!(None, Decoding::moves(&rules, vec![0, 0, 1]));
+    assert_eq!(None, Decoding::moves(&rules, vec![0, 1, 3]));
+    assert_eq!(None, Decoding::Moves(vec![1, 0]).our_move(&rules, 0, 'Z'));
+    assert!(analyses.contains(&Analysis {
+        decoding: Decoding::Outcomes,
+        score: 12
+    }));
 
-    let (part1, part2) = part12(include_str!("input"));
+    let totals = |decoding| {
+        breakdown::breakdown(&input!("input_test"), &rules, &decoding)
+            .iter()
+            .map(|row| row.total)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(vec![8, 9, 15], totals(Decoding::part1(&rules)));
+    assert_eq!(vec![4, 5, 12], totals(Decoding::Outcomes));
+    assert_eq!(
+        "Round  Opponent  Ours      Outcome  Shape pts Outcome pts   Total\n    1  Rock      Paper     Win              2           6       8\n",
+        breakdown::to_table(
+            &breakdown::breakdown("A Y", &rules, &Decoding::part1(&rules)),
+            &rules
+        )
+    );
+    assert_eq!(
+        "[{\"round\":1,\"opponent\":\"Rock\",\"ours\":\"Paper\",\"outcome\":\"win\",\"shape_points\":2,\"outcome_points\":6,\"total\":8}]",
+        breakdown::to_json(
+            &breakdown::breakdown("A Y", &rules, &Decoding::part1(&rules)),
+            &rules
+        )
+    );
+
+    let opponents = opponent_moves(&input!("input_test"), &rules);
+    let synthesize =
+        |objective, max_wins| synthesis::synthesize(&opponents, &rules, objective, max_wins);
+    assert_eq!(24, synthesize(Objective::Max, None).unwrap().score);
+    assert_eq!(15, synthesize(Objective::Max, Some(0)).unwrap().score);
+    assert_eq!(6, synthesize(Objective::Min, None).unwrap().score);
+    let plan = synthesize(Objective::Exact(16), Some(1)).unwrap();
+    assert_eq!(
+        16,
+        plan.moves
+            .iter()
+            .zip(&opponents)
+            .map(|(&ours, &theirs)| rules.score(ours, theirs))
+            .sum::<u32>()
+    );
+    assert!(plan.wins <= 1);
+    assert!(synthesize(Objective::Exact(16), Some(0)).is_err());
+    assert_eq!(
+        Some(24),
+        synthesize(Objective::Exact(25), None)
+            .unwrap_err()
+            .closest_below
+    );
+
+    let mut rock = Cycle::new("Rock", vec![0]);
+    assert_eq!(
+        (76, 13),
+        tournament::play_match(&rules, &mut FrequencyCounter, &mut rock, 10)
+    );
+    assert_eq!(
+        (7, 21),
+        tournament::play_match(
+            &rules,
+            &mut Copycat,
+            &mut Cycle::new("Cycle", vec![0, 1, 2]),
+            3
+        )
+    );
+    let mut random = SeededRandom::new(42);
+    assert_eq!(
+        tournament::play_match(&rules, &mut random, &mut rock, 100),
+        tournament::play_match(&rules, &mut random, &mut rock, 100)
+    );
+    let mut strategies: Vec<Box<dyn Strategy>> = vec![
+        Box::new(Cycle::guide("Guide", &input!("input_test"), &rules)),
+        Box::new(rock),
+        Box::new(FrequencyCounter),
+    ];
+    let standings = tournament::round_robin(&rules, &mut strategies, 10);
+    assert_eq!("Guide", standings[0].name);
+    assert_eq!(
+        (2, 0, 0, 124),
+        (
+            standings[0].wins,
+            standings[0].draws,
+            standings[0].losses,
+            standings[0].score
+        )
+    );
+
+    let (part1, part2) = part12(&input!("input"));
 
     println!("Day 02");
     println!("Part 1: {}", part1);
     println!("Part 2: {}", part2);
+    println!("Decodings of the strategy guide:");
+    print!(
+        "{}",
+        cipher::report(&cipher::analyze(&input!("input"), &rules), &rules)
+    );
+    let opponents = opponent_moves(&input!("input"), &rules);
+    let max_wins = opponents.len() as u32 / 3;
+    match synthesis::synthesize(&opponents, &rules, Objective::Max, Some(max_wins)) {
+        Ok(plan) => println!(
+            "Highest score with at most {} wins: {}",
+            max_wins, plan.score
+        ),
+        Err(e) => println!("{}", e),
+    }
+
+    let mut strategies: Vec<Box<dyn Strategy>> = vec![
+        Box::new(Cycle::guide("Strategy guide", &input!("input"), &rules)),
+        Box::new(Cycle::opponent("Opponent column", &input!("input"), &rules)),
+        Box::new(Cycle::new("Rock", vec![0])),
+        Box::new(Cycle::new("Rock, Paper, Scissors", vec![0, 1, 2])),
+        Box::new(FrequencyCounter),
+        Box::new(Copycat),
+        Box::new(SeededRandom::new(2022)),
+    ];
+    println!("Tournament standings:");
+    print!(
+        "{}",
+        tournament::report(&tournament::round_robin(&rules, &mut strategies, 1000))
+    );
+
+    // Per round scores of both parts on request
+    if args.iter().any(|arg| arg == "--breakdown") {
+        let json = args.iter().any(|arg| arg == "--json");
+        for decoding in [Decoding::part1(&rules), Decoding::Outcomes] {
+            let rows = breakdown::breakdown(&input!("input"), &rules, &decoding);
+            if json {
+                println!("{}", breakdown::to_json(&rows, &rules));
+            } else {
+                println!("{}", decoding.describe(&rules));
+                print!("{}", breakdown::to_table(&rows, &rules));
+            }
+        }
+    }
 }
@@ -0,0 +1,184 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+/// Outcome of a round from our point of view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+}
+
+/// Response symbols meaning the desired outcome in part 2.
+pub const OUTCOME_SYMBOLS: [char; 3] = ['X', 'Y', 'Z'];
+
+/// Rule table of a hand game where any two different moves have a winner.
+/// Moves are referred to by their index in the table.
+#[derive(Debug, Clone)]
+pub struct Rules {
+    moves: Vec<String>,
+    // beats[a][b] is true if a beats b
+    beats: Vec<Vec<bool>>,
+    shape_scores: Vec<u32>,
+    // Indexed by Outcome
+    outcome_scores: [u32; 3],
+    opponent_symbols: Vec<char>,
+    response_symbols: Vec<char>,
+}
+
+impl Rules {
+    /// `beats` lists the (winner, loser) pairs by move name. The symbols of
+    /// the opponent and our response column encode the moves in order.
+    pub fn new(
+        moves: &[&str],
+        beats: &[(&str, &str)],
+        shape_scores: &[u32],
+        outcome_scores: [u32; 3],
+        opponent_symbols: &str,
+        response_symbols: &str,
+    ) -> Self {
+        let index = |name: &str| {
+            moves
+                .iter()
+                .position(|m| *m == name)
+                .unwrap_or_else(|| panic!("Unknown move {}", name))
+        };
+
+        let mut table = vec![vec![false; moves.len()]; moves.len()];
+        for (winner, loser) in beats {
+            table[index(winner)][index(loser)] = true;
+        }
+
+        for a in 0..moves.len() {
+            assert!(!table[a][a], "{} can't beat itself", moves[a]);
+            for b in a + 1..moves.len() {
+                assert!(
+                    table[a][b] != table[b][a],
+                    "Exactly one of {} and {} must win",
+                    moves[a],
+                    moves[b]
+                );
+            }
+        }
+        assert_eq!(moves.len(), shape_scores.len(), "Shape score per move");
+        assert_eq!(
+            moves.len(),
+            opponent_symbols.chars().count(),
+            "Opponent symbol per move"
+        );
+        assert_eq!(
+            moves.len(),
+            response_symbols.chars().count(),
+            "Response symbol per move"
+        );
+
+        Self {
+            moves: moves.iter().map(|m| m.to_string()).collect(),
+            beats: table,
+            shape_scores: shape_scores.to_vec(),
+            outcome_scores,
+            opponent_symbols: opponent_symbols.chars().collect(),
+            response_symbols: response_symbols.chars().collect(),
+        }
+    }
+
+    /// Balanced game of an odd number of moves, where each move beats the
+    /// ones an odd number of steps before it in the cycle. Shapes score 1, 2,
+    /// ... in order, outcomes the puzzle's 0, 3 and 6.
+    pub fn cyclic(moves: &[&str], opponent_symbols: &str, response_symbols: &str) -> Self {
+        assert!(
+            moves.len() % 2 == 1,
+            "Cyclic games have an odd number of moves"
+        );
+
+        let n = moves.len();
+        let beats: Vec<(&str, &str)> = (0..n)
+            .flat_map(|a| (0..n).map(move |b| (a, b)))
+            .filter(|(a, b)| ((a + n - b) % n) % 2 == 1)
+            .map(|(a, b)| (moves[a], moves[b]))
+            .collect();
+        let shape_scores: Vec<u32> = (1..=n as u32).collect();
+
+        Self::new(
+            moves,
+            &beats,
+            &shape_scores,
+            [0, 3, 6],
+            opponent_symbols,
+            response_symbols,
+        )
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Self::cyclic(&["Rock", "Paper", "Scissors"], "ABC", "XYZ")
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::cyclic(
+            &["Rock", "Paper", "Scissors", "Spock", "Lizard"],
+            "ABCDE",
+            "VWXYZ",
+        )
+    }
+
+    pub fn move_count(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn move_name(&self, index: usize) -> &str {
+        &self.moves[index]
+    }
+
+    pub fn shape_score(&self, ours: usize) -> u32 {
+        self.shape_scores[ours]
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> u32 {
+        self.outcome_scores[outcome as usize]
+    }
+
+    pub fn outcome(&self, ours: usize, theirs: usize) -> Outcome {
+        if ours == theirs {
+            Outcome::Draw
+        } else if self.beats[ours][theirs] {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    pub fn score(&self, ours: usize, theirs: usize) -> u32 {
+        self.shape_score(ours) + self.outcome_score(self.outcome(ours, theirs))
+    }
+
+    /// Our move leading to `outcome`, the first one in table order if there
+    /// are more.
+    pub fn response(&self, theirs: usize, outcome: Outcome) -> Option<usize> {
+        (0..self.move_count()).find(|&ours| self.outcome(ours, theirs) == outcome)
+    }
+
+    pub fn opponent_move(&self, symbol: char) -> Option<usize> {
+        self.opponent_symbols.iter().position(|&s| s == symbol)
+    }
+
+    pub fn response_symbols(&self) -> &[char] {
+        &self.response_symbols
+    }
+
+    /// Our move according to the part 1 reading of the guide.
+    pub fn response_move(&self, symbol: char) -> Option<usize> {
+        self.response_symbols.iter().position(|&s| s == symbol)
+    }
+
+    /// Desired outcome according to the part 2 reading of the guide.
+    pub fn desired_outcome(symbol: char) -> Option<Outcome> {
+        OUTCOME_SYMBOLS
+            .iter()
+            .position(|&s| s == symbol)
+            .map(|index| Outcome::ALL[index])
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Display;
+
+use crate::{Outcome, Rules};
+
+// Marks unreachable scores
+const UNREACHABLE: u32 = u32::MAX;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    Exact(u32),
+    Max,
+    Min,
+}
+
+/// Our moves against the opponent's sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub moves: Vec<usize>,
+    pub score: u32,
+    pub wins: u32,
+}
+
+/// No response sequence meets the objective. As every sequence was
+/// considered, the closest reachable scores are given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoPlan {
+    pub objective: Objective,
+    pub max_wins: Option<u32>,
+    pub closest_below: Option<u32>,
+    pub closest_above: Option<u32>,
+}
+
+impl Display for NoPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No strategy reaches {:?}", self.objective)?;
+        if let Some(max_wins) = self.max_wins {
+            write!(f, " with at most {} wins", max_wins)?;
+        }
+        match (self.closest_below, self.closest_above) {
+            (None, None) => write!(f, ", no score is reachable"),
+            (below, above) => {
+                let format = |score: Option<u32>| score.map_or("-".to_string(), |s| s.to_string());
+                write!(
+                    f,
+                    ", closest reachable scores: {} and {}",
+                    format(below),
+                    format(above)
+                )
+            }
+        }
+    }
+}
+
+/// Score and win count of each of our moves in a round.
+type Options = Vec<(u32, u32)>;
+
+/// Fewest wins needed to reach each total score over the rounds.
+fn min_wins(rounds: &[Options]) -> Vec<u32> {
+    let mut res = vec![0];
+
+    for options in rounds {
+        let max_score = options.iter().map(|(score, _)| *score).max().unwrap_or(0);
+        let mut next = vec![UNREACHABLE; res.len() + max_score as usize];
+        for (total, &wins) in res.iter().enumerate() {
+            if wins == UNREACHABLE {
+                continue;
+            }
+            for &(score, win) in options {
+                let entry = &mut next[total + score as usize];
+                *entry = (*entry).min(wins + win);
+            }
+        }
+        res = next;
+    }
+
+    res
+}
+
+/// Fills `moves` with a plan of the rounds scoring `target` with at most
+/// `max_wins` wins. The rounds are halved to keep the memory linear in the
+/// number of scores (Hirschberg's technique).
+fn solve(rounds: &[Options], target: u32, max_wins: u32, moves: &mut [usize]) -> bool {
+    match rounds {
+        [] => target == 0,
+        [options] => {
+            let best = options
+                .iter()
+                .enumerate()
+                .filter(|(_, &(score, win))| score == target && win <= max_wins)
+                .min_by_key(|(_, &(_, win))| win);
+            match best {
+                Some((ours, _)) => {
+                    moves[0] = ours;
+                    true
+                }
+                None => false,
+            }
+        }
+        _ => {
+            let middle = rounds.len() / 2;
+            let (left, right) = (min_wins(&rounds[..middle]), min_wins(&rounds[middle..]));
+
+            let split = (0..left.len())
+                .filter(|&score| score as u32 <= target)
+                .filter_map(|score| {
+                    let rest = target as usize - score;
+                    let (a, b) = (left[score], *right.get(rest)?);
+                    (a != UNREACHABLE && b != UNREACHABLE && a + b <= max_wins).then_some((
+                        score as u32,
+                        a,
+                        b,
+                    ))
+                })
+                .min_by_key(|(_, a, b)| a + b);
+
+            match split {
+                Some((score, a, b)) => {
+                    let (left_moves, right_moves) = moves.split_at_mut(middle);
+                    solve(&rounds[..middle], score, a, left_moves)
+                        && solve(&rounds[middle..], target - score, b, right_moves)
+                }
+                None => false,
+            }
+        }
+    }
+}
+
+/// Our responses to the opponent's moves meeting the objective with at most
+/// `max_wins` wins, or proof that there are none.
+pub fn synthesize(
+    opponents: &[usize],
+    rules: &Rules,
+    objective: Objective,
+    max_wins: Option<u32>,
+) -> Result<Plan, NoPlan> {
+    let rounds: Vec<Options> = opponents
+        .iter()
+        .map(|&theirs| {
+            (0..rules.move_count())
+                .map(|ours| {
+                    let win = rules.outcome(ours, theirs) == Outcome::Win;
+                    (rules.score(ours, theirs), win as u32)
+                })
+                .collect()
+        })
+        .collect();
+
+    let limit = max_wins.unwrap_or(u32::MAX - 1);
+    let reachable: Vec<u32> = min_wins(&rounds)
+        .iter()
+        .enumerate()
+        .filter(|(_, &wins)| wins <= limit)
+        .map(|(score, _)| score as u32)
+        .collect();
+
+    let target = match objective {
+        Objective::Exact(target) => reachable.binary_search(&target).ok().map(|_| target),
+        Objective::Max => reachable.last().copied(),
+        Objective::Min => reachable.first().copied(),
+    };
+    let Some(target) = target else {
+        let target = match objective {
+            Objective::Exact(target) => target,
+            _ => 0,
+        };
+        return Err(NoPlan {
+            objective,
+            max_wins,
+            closest_below: reachable.iter().rev().find(|&&s| s < target).copied(),
+            closest_above: reachable.iter().find(|&&s| s > target).copied(),
+        });
+    };
+
+    let mut moves = vec![0; opponents.len()];
+    assert!(solve(&rounds, target, limit, &mut moves));
+
+    let wins = moves
+        .iter()
+        .zip(opponents)
+        .filter(|(&ours, &theirs)| rules.outcome(ours, theirs) == Outcome::Win)
+        .count() as u32;
+
+    Ok(Plan {
+        moves,
+        score: target,
+        wins,
+    })
+}
@@ -0,0 +1,249 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::cmp::{Ordering, Reverse};
+
+use crate::{opponent_moves, parse_rounds, Outcome, Rules};
+
+// Match points of a won and a drawn match
+const WIN_POINTS: u32 = 3;
+const DRAW_POINTS: u32 = 1;
+
+/// A player choosing its moves by the rounds played so far in the match.
+pub trait Strategy {
+    fn name(&self) -> String;
+
+    /// Forgets everything before a new match.
+    fn reset(&mut self) {}
+
+    /// `history` holds the (our move, their move) pairs of the previous
+    /// rounds.
+    fn next_move(&mut self, rules: &Rules, history: &[(usize, usize)]) -> usize;
+}
+
+/// Plays the same moves over and over.
+pub struct Cycle {
+    name: String,
+    moves: Vec<usize>,
+}
+
+impl Cycle {
+    pub fn new(name: &str, moves: Vec<usize>) -> Self {
+        assert!(!moves.is_empty(), "A cycle needs moves");
+        Self {
+            name: name.to_string(),
+            moves,
+        }
+    }
+
+    /// Our moves of the strategy guide, read as in part 1.
+    pub fn guide(name: &str, input: &str, rules: &Rules) -> Self {
+        let moves = parse_rounds(input)
+            .iter()
+            .map(|round| {
+                rules
+                    .response_move(round.response)
+                    .unwrap_or_else(|| panic!("Invalid input {:?}", round))
+            })
+            .collect();
+
+        Self::new(name, moves)
+    }
+
+    /// Moves of the opponent column of the strategy guide.
+    pub fn opponent(name: &str, input: &str, rules: &Rules) -> Self {
+        Self::new(name, opponent_moves(input, rules))
+    }
+}
+
+impl Strategy for Cycle {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn next_move(&mut self, _rules: &Rules, history: &[(usize, usize)]) -> usize {
+        self.moves[history.len() % self.moves.len()]
+    }
+}
+
+/// Beats the opponent's most frequent move so far, opening with the first
+/// move.
+pub struct FrequencyCounter;
+
+impl Strategy for FrequencyCounter {
+    fn name(&self) -> String {
+        "Frequency counter".to_string()
+    }
+
+    fn next_move(&mut self, rules: &Rules, history: &[(usize, usize)]) -> usize {
+        let mut counts = vec![0; rules.move_count()];
+        for &(_, theirs) in history {
+            counts[theirs] += 1;
+        }
+
+        if history.is_empty() {
+            return 0;
+        }
+
+        // The earliest of the most frequent moves
+        let most_frequent = (0..counts.len())
+            .max_by_key(|&m| (counts[m], Reverse(m)))
+            .unwrap();
+        rules.response(most_frequent, Outcome::Win).unwrap_or(0)
+    }
+}
+
+/// Repeats the opponent's previous move, opening with the first move.
+pub struct Copycat;
+
+impl Strategy for Copycat {
+    fn name(&self) -> String {
+        "Copycat".to_string()
+    }
+
+    fn next_move(&mut self, _rules: &Rules, history: &[(usize, usize)]) -> usize {
+        history.last().map_or(0, |&(_, theirs)| theirs)
+    }
+}
+
+/// Uniformly random moves from a SplitMix64 generator, restarted from the
+/// seed in every match.
+pub struct SeededRandom {
+    seed: u64,
+    state: u64,
+}
+
+impl SeededRandom {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl Strategy for SeededRandom {
+    fn name(&self) -> String {
+        format!("Random (seed {})", self.seed)
+    }
+
+    fn reset(&mut self) {
+        self.state = self.seed;
+    }
+
+    fn next_move(&mut self, rules: &Rules, _history: &[(usize, usize)]) -> usize {
+        (self.next_u64() % rules.move_count() as u64) as usize
+    }
+}
+
+/// Total scores of the two players.
+pub fn play_match(
+    rules: &Rules,
+    a: &mut dyn Strategy,
+    b: &mut dyn Strategy,
+    rounds: usize,
+) -> (u64, u64) {
+    a.reset();
+    b.reset();
+
+    let mut history_a = Vec::with_capacity(rounds);
+    let mut history_b = Vec::with_capacity(rounds);
+    let (mut score_a, mut score_b) = (0, 0);
+    for _ in 0..rounds {
+        let (move_a, move_b) = (
+            a.next_move(rules, &history_a),
+            b.next_move(rules, &history_b),
+        );
+        score_a += rules.score(move_a, move_b) as u64;
+        score_b += rules.score(move_b, move_a) as u64;
+        history_a.push((move_a, move_b));
+        history_b.push((move_b, move_a));
+    }
+
+    (score_a, score_b)
+}
+
+/// Results of a player over the tournament.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub name: String,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    /// 3 points for a won match, 1 for a draw
+    pub points: u32,
+    /// Sum of the scores of all matches
+    pub score: u64,
+}
+
+/// Every strategy plays a match of `rounds` rounds against every other.
+/// Standings are ordered by points, then total score.
+pub fn round_robin(
+    rules: &Rules,
+    strategies: &mut [Box<dyn Strategy>],
+    rounds: usize,
+) -> Vec<Standing> {
+    let mut res: Vec<Standing> = strategies
+        .iter()
+        .map(|strategy| Standing {
+            name: strategy.name(),
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            points: 0,
+            score: 0,
+        })
+        .collect();
+
+    for b in 1..strategies.len() {
+        let (left, right) = strategies.split_at_mut(b);
+        for (a, strategy) in left.iter_mut().enumerate() {
+            let scores = play_match(rules, strategy.as_mut(), right[0].as_mut(), rounds);
+
+            for (player, score, other) in [(a, scores.0, scores.1), (b, scores.1, scores.0)] {
+                let standing = &mut res[player];
+                standing.score += score;
+                match score.cmp(&other) {
+                    Ordering::Greater => {
+                        standing.wins += 1;
+                        standing.points += WIN_POINTS;
+                    }
+                    Ordering::Equal => {
+                        standing.draws += 1;
+                        standing.points += DRAW_POINTS;
+                    }
+                    Ordering::Less => standing.losses += 1,
+                }
+            }
+        }
+    }
+
+    res.sort_by_key(|standing| (Reverse(standing.points), Reverse(standing.score)));
+    res
+}
+
+pub fn report(standings: &[Standing]) -> String {
+    let mut res = format!(
+        "{:<4}{:<24}{:>4}{:>4}{:>4}{:>8}{:>10}\n",
+        "#", "Strategy", "W", "D", "L", "Points", "Score"
+    );
+    for (rank, standing) in standings.iter().enumerate() {
+        res += &format!(
+            "{:<4}{:<24}{:>4}{:>4}{:>4}{:>8}{:>10}\n",
+            rank + 1,
+            standing.name,
+            standing.wins,
+            standing.draws,
+            standing.losses,
+            standing.points,
+            standing.score
+        );
+    }
+
+    res
+}
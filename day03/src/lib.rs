@@ -0,0 +1,46 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::alloc;
+
+fn get_value(c: char) -> u32 {
+    if c.is_lowercase() {
+        ((c as u8) - b'a' + 1) as u32
+    } else {
+        ((c as u8) - b'A' + 27) as u32
+    }
+}
+
+pub fn part12(input: &str) -> (u32, u32) {
+    let rucksacks: Vec<&str> = alloc::phase("parse", || input.split_whitespace().collect());
+
+    (
+        rucksacks
+            .iter()
+            .map(|line| {
+                let (first_half, second_half) = line.split_at(line.len() / 2);
+
+                let mut duplicates = String::new();
+
+                for c in first_half.chars() {
+                    if second_half.contains(c) && !duplicates.contains(c) {
+                        duplicates.push(c);
+                    }
+                }
+
+                duplicates.chars().map(get_value).sum::<u32>()
+            })
+            .sum(),
+        rucksacks
+            .chunks_exact(3)
+            .map(|lines| {
+                let [l1, l2, l3] = [lines[0], lines[1], lines[2]];
+                l1.chars()
+                    .filter(|c| l2.contains(*c) && l3.contains(*c))
+                    .map(get_value)
+                    .take(1)
+                    .sum::<u32>()
+            })
+            .sum(),
+    )
+}
@@ -0,0 +1,42 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::alloc;
+
+/// Section ranges of the two elves of each pair.
+fn parse_input(input: &str) -> Vec<[u32; 4]> {
+    input
+        .split("\n")
+        .filter(|l| !l.is_empty())
+        .map(|line| {
+            let parts: Vec<&str> = line.split(",").collect();
+
+            let range0: Vec<&str> = parts[0].split("-").collect();
+            let range1: Vec<&str> = parts[1].split("-").collect();
+
+            [
+                range0[0].parse().unwrap(),
+                range0[1].parse().unwrap(),
+                range1[0].parse().unwrap(),
+                range1[1].parse().unwrap(),
+            ]
+        })
+        .collect()
+}
+
+pub fn part12(input: &str) -> (u32, u32) {
+    alloc::phase("parse", || parse_input(input))
+        .into_iter()
+        .map(|[range0_start, range0_end, range1_start, range1_end]| {
+            (
+                ((range0_start <= range1_start && range1_end <= range0_end)
+                    || (range1_start <= range0_start && range0_end <= range1_end))
+                    as u32,
+                ((range0_start <= range1_start && range1_start <= range0_end)
+                    || (range1_start <= range0_start && range0_start <= range1_end))
+                    as u32,
+            )
+        })
+        .reduce(|acc, i| (acc.0 + i.0, acc.1 + i.1))
+        .unwrap()
+}
@@ -0,0 +1,102 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::alloc;
+
+/// Crate count, source and target stack, stacks numbered from 1.
+type Move = (usize, usize, usize);
+
+/// Starting stacks bottom first, and the moves.
+fn parse_input(input: &str) -> (Vec<Vec<char>>, Vec<Move>) {
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); 9];
+    let mut moves = Vec::new();
+    let mut stack_count = stacks.len();
+
+    let mut init = true;
+    for line in input.split("\n") {
+        if line.is_empty() {
+            init = false;
+            continue;
+        }
+
+        if init {
+            if line.trim_start().starts_with(char::is_numeric) {
+                stack_count = line.split_whitespace().count();
+            }
+
+            for (i, c) in line
+                .chars()
+                .enumerate()
+                .skip(1)
+                .step_by(4)
+                .filter(|(_i, c)| !c.is_numeric() && !c.is_whitespace())
+            {
+                stacks[i / 4].insert(0, c);
+            }
+        } else {
+            let line_parts: Vec<&str> = line.split(" ").collect();
+            moves.push((
+                line_parts[1].parse().unwrap(),
+                line_parts[3].parse().unwrap(),
+                line_parts[5].parse().unwrap(),
+            ));
+        }
+    }
+
+    stacks.truncate(stack_count);
+    (stacks, moves)
+}
+
+pub fn rearrange(input: &str, reverse: bool) -> Vec<Vec<char>> {
+    let (mut stacks, moves) = alloc::phase("parse", || parse_input(input));
+
+    for (count, from, to) in moves {
+        let mut stack_to_move: Vec<_> = (0..count)
+            .map(|_| stacks[from - 1].pop().unwrap())
+            .collect();
+
+        if reverse {
+            stack_to_move.reverse();
+        }
+
+        stacks[to - 1].append(&mut stack_to_move);
+    }
+
+    stacks
+}
+
+/// Draws the stacks in the same format as the puzzle input.
+pub fn draw_stacks(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+
+    let mut res = String::new();
+    for level in (0..height).rev() {
+        let line = stacks
+            .iter()
+            .map(|s| match s.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => String::from("   "),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        res.push_str(line.trim_end());
+        res.push('\n');
+    }
+
+    let numbers = (1..=stacks.len())
+        .map(|i| format!(" {} ", i))
+        .collect::<Vec<_>>()
+        .join(" ");
+    res.push_str(&numbers);
+    res.push('\n');
+
+    res
+}
+
+pub fn part12(input: &str, reverse: bool) -> String {
+    rearrange(input, reverse)
+        .iter()
+        .filter(|s| !s.is_empty())
+        .map(|s| s[s.len() - 1])
+        .collect::<String>()
+}
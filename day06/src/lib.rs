@@ -0,0 +1,16 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::alloc;
+
+pub fn part12(input: &str, window: usize) -> usize {
+    let chars: Vec<char> = alloc::phase("parse", || input.chars().collect());
+
+    chars
+        .windows(window)
+        .enumerate()
+        .filter(|(_i, w)| w.iter().all(|c| w.iter().filter(|a| *a == c).count() == 1))
+        .map(|i| i.0 + window)
+        .next()
+        .unwrap()
+}
@@ -1,17 +1,8 @@
 // Copyright (c) 2022, Kis Imre. All rights reserved.
 // SPDX-License-Identifier: MIT
 
-fn part12(input: &str, window: usize) -> usize {
-    let chars: Vec<char> = input.chars().collect();
-
-    chars
-        .windows(window)
-        .enumerate()
-        .filter(|(_i, w)| w.iter().all(|c| w.iter().filter(|a| *a == c).count() == 1))
-        .map(|i| i.0 + window)
-        .next()
-        .unwrap()
-}
+use common::input;
+use day06::part12;
 
 fn main() {
     assert_eq!(7, part12("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4));
@@ -26,8 +17,8 @@ fn main() {
     assert_eq!(29, part12("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14));
     assert_eq!(26, part12("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14));
 
-    let part1 = part12(include_str!("input"), 4);
-    let part2 = part12(include_str!("input"), 14);
+    let part1 = part12(&input!("input"), 4);
+    let part2 = part12(&input!("input"), 14);
 
     println!("Day 06");
     println!("Part 1: {}", part1);
@@ -0,0 +1,68 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::alloc;
+
+fn update_strength(strength: &mut i32, x: i32, cycle: u32, display: &mut [String]) {
+    if cycle > 240 {
+        return;
+    }
+
+    if cycle >= 20 && (cycle - 20).is_multiple_of(40) {
+        *strength += x * cycle as i32;
+    }
+
+    let pos = (cycle - 1) as i32;
+    if ((pos % 40) - x).abs() <= 1 {
+        display[(pos / 40) as usize].push('#');
+    } else {
+        display[(pos / 40) as usize].push('.');
+    }
+}
+
+/// Instructions, `None` for `noop` and the value of `addx`.
+fn parse_input(input: &str) -> Vec<Option<i32>> {
+    input
+        .split("\n")
+        .filter(|l| !l.is_empty())
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split(" ").collect();
+            match parts[0] {
+                "noop" => Some(None),
+                "addx" => Some(Some(parts[1].parse().unwrap())),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+pub fn part12(input: &str) -> (i32, Vec<String>) {
+    let instructions = alloc::phase("parse", || parse_input(input));
+    let mut display = vec![String::new(); 6];
+
+    let mut strength: i32 = 0;
+    let mut x: i32 = 1;
+    let mut cycle: u32 = 1;
+
+    update_strength(&mut strength, x, cycle, &mut display);
+    for instruction in instructions {
+        match instruction {
+            None => {
+                cycle += 1;
+                update_strength(&mut strength, x, cycle, &mut display);
+            }
+            Some(value) => {
+                cycle += 1;
+
+                update_strength(&mut strength, x, cycle, &mut display);
+
+                x += value;
+                cycle += 1;
+
+                update_strength(&mut strength, x, cycle, &mut display);
+            }
+        }
+    }
+
+    (strength, display)
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::{
+    number::{checked_lcm_all, Mod},
+    overflow::{CheckedInt, Overflow},
+};
+
+/// `new = old <operator> <operand>`, the operand being `old` if missing.
+struct Operation {
+    operand: Option<u64>,
+    is_multiplication: bool,
+}
+
+impl Operation {
+    fn apply(&self, old: u64) -> Result<u64, Overflow> {
+        let operand = self.operand.unwrap_or(old);
+        if self.is_multiplication {
+            old.try_mul(operand)
+        } else {
+            old.try_add(operand)
+        }
+    }
+
+    fn apply_mod(&self, old: Mod) -> Mod {
+        let operand = self
+            .operand
+            .map_or(old, |operand| Mod::from_u64(operand, old.modulus()));
+        if self.is_multiplication {
+            old * operand
+        } else {
+            old + operand
+        }
+    }
+}
+
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    targets: (usize, usize),
+    throws: usize,
+}
+
+impl Monkey {
+    fn new(items: Vec<u64>, operation: Operation, divisor: u64, targets: (usize, usize)) -> Self {
+        Self {
+            items,
+            operation,
+            throws: 0,
+            divisor,
+            targets,
+        }
+    }
+
+    fn throws(&mut self, simplifier: u64, divisor: u64) -> Result<Vec<(u64, usize)>, Overflow> {
+        self.items
+            .iter()
+            .map(|w| {
+                let w = if divisor == 1 {
+                    // Without the division only the residues matter
+                    self.operation
+                        .apply_mod(Mod::from_u64(*w, simplifier))
+                        .value()
+                } else {
+                    (self.operation.apply(*w)? / divisor) % simplifier
+                };
+                Ok((
+                    w,
+                    if w.is_multiple_of(self.divisor) {
+                        self.targets.0
+                    } else {
+                        self.targets.1
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    fn add(&mut self, worry: u64) {
+        self.items.push(worry);
+    }
+
+    fn clear(&mut self) {
+        self.throws += self.items.len();
+        self.items.clear();
+    }
+}
+
+fn parse_operation(operation: &str) -> Operation {
+    let parts: Vec<&str> = operation
+        .strip_prefix("new = old ")
+        .unwrap_or_else(|| panic!("Invalid operation {:?}", operation))
+        .split(' ')
+        .collect();
+
+    Operation {
+        operand: parts[1].parse().ok(),
+        is_multiplication: match parts[0] {
+            "*" => true,
+            "+" => false,
+            op => panic!("Invalid operator {}", op),
+        },
+    }
+}
+
+pub fn parse_input(input: &str) -> Vec<Monkey> {
+    input
+        .split("\n\n")
+        .map(|block| {
+            let lines: Vec<&str> = block.lines().map(|line| line.trim()).collect();
+            let value_of = |index: usize, prefix: &str| {
+                lines[index]
+                    .strip_prefix(prefix)
+                    .unwrap_or_else(|| panic!("Invalid monkey line {:?}", lines[index]))
+            };
+
+            Monkey::new(
+                value_of(1, "Starting items: ")
+                    .split(", ")
+                    .map(|item| item.parse().unwrap())
+                    .collect(),
+                parse_operation(value_of(2, "Operation: ")),
+                value_of(3, "Test: divisible by ").parse().unwrap(),
+                (
+                    value_of(4, "If true: throw to monkey ").parse().unwrap(),
+                    value_of(5, "If false: throw to monkey ").parse().unwrap(),
+                ),
+            )
+        })
+        .collect()
+}
+
+pub fn part12(mut monkeys: Vec<Monkey>, turns: u32, divisor: u64) -> Result<usize, Overflow> {
+    // Divisibility by every monkey's divisor is kept modulo their lcm
+    let simplifier = checked_lcm_all(monkeys.iter().map(|m| m.divisor))?;
+    for _turn in 0..turns {
+        for monkey_index in 0..monkeys.len() {
+            let throws = monkeys[monkey_index].throws(simplifier, divisor)?;
+
+            for (worry, to_monkey) in throws {
+                monkeys[to_monkey].add(worry);
+            }
+
+            monkeys[monkey_index].clear();
+        }
+    }
+
+    let mut throws = monkeys.iter().map(|t| t.throws).collect::<Vec<usize>>();
+    throws.sort();
+    throws.reverse();
+
+    throws[0].try_mul(throws[1])
+}
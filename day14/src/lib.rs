@@ -0,0 +1,139 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{cmp::max, cmp::min, collections::BTreeSet};
+
+use common::alloc;
+
+fn parse_input(input: &str) -> BTreeSet<(i32, i32)> {
+    let mut tiles = BTreeSet::new();
+
+    for line in input.split("\n") {
+        let rock_paths: Vec<&str> = line.split(" -> ").collect();
+        for (path_start, path_end) in rock_paths.iter().zip(rock_paths.iter().skip(1)) {
+            let start = path_start
+                .split(",")
+                .map(|x| x.parse::<i32>().unwrap())
+                .collect::<Vec<_>>();
+            let end = path_end
+                .split(",")
+                .map(|x| x.parse::<i32>().unwrap())
+                .collect::<Vec<_>>();
+
+            if start[0] == end[0] {
+                for y in min(start[1], end[1])..=max(start[1], end[1]) {
+                    tiles.insert((start[0], y));
+                }
+            } else {
+                for x in min(start[0], end[0])..=max(start[0], end[0]) {
+                    tiles.insert((x, start[1]));
+                }
+            }
+        }
+    }
+
+    tiles
+}
+
+const SAND_SOURCE: (i32, i32) = (500, 0);
+
+pub struct Cave {
+    rocks: BTreeSet<(i32, i32)>,
+    sand: BTreeSet<(i32, i32)>,
+    start_of_abyss: i32,
+    has_floor: bool,
+}
+
+impl Cave {
+    pub fn new(input: &str, has_floor: bool) -> Self {
+        let rocks = parse_input(input);
+        let start_of_abyss = *rocks.iter().map(|(_x, y)| y).max().unwrap();
+
+        Self {
+            rocks,
+            sand: BTreeSet::new(),
+            start_of_abyss,
+            has_floor,
+        }
+    }
+
+    /// Drops a unit of sand from the source. Returns false if it does not
+    /// come to rest, i.e. it falls into the abyss or the source is blocked.
+    pub fn drop_sand(&mut self) -> bool {
+        if self.sand.contains(&SAND_SOURCE) {
+            return false;
+        }
+
+        let floor = self.start_of_abyss + 2;
+        let mut sand = SAND_SOURCE;
+
+        while let Some(new_pos) = [(0, 1), (-1, 1), (1, 1)]
+            .iter()
+            .map(|offset| (sand.0 + offset.0, sand.1 + offset.1))
+            .find(|p| p.1 != floor && !self.rocks.contains(p) && !self.sand.contains(p))
+        {
+            sand = new_pos;
+
+            if !self.has_floor && sand.1 > self.start_of_abyss {
+                return false;
+            }
+        }
+
+        self.sand.insert(sand);
+        true
+    }
+
+    pub fn sand_count(&self) -> usize {
+        self.sand.len()
+    }
+
+    pub fn draw(&self) -> String {
+        let tiles = || {
+            self.rocks
+                .iter()
+                .chain(self.sand.iter())
+                .chain([&SAND_SOURCE])
+        };
+        let min_x = tiles().map(|(x, _y)| *x).min().unwrap();
+        let max_x = tiles().map(|(x, _y)| *x).max().unwrap();
+        let max_y = if self.has_floor {
+            self.start_of_abyss + 2
+        } else {
+            self.start_of_abyss
+        };
+
+        let mut res = String::new();
+        for y in 0..=max_y {
+            for x in min_x..=max_x {
+                res.push(
+                    if self.rocks.contains(&(x, y)) || y == self.start_of_abyss + 2 {
+                        '#'
+                    } else if self.sand.contains(&(x, y)) {
+                        'o'
+                    } else if (x, y) == SAND_SOURCE {
+                        '+'
+                    } else {
+                        '.'
+                    },
+                );
+            }
+            res.push('\n');
+        }
+
+        res
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    let mut cave = alloc::phase("parse", || Cave::new(input, false));
+    while cave.drop_sand() {}
+
+    cave.sand_count()
+}
+
+pub fn part2(input: &str) -> usize {
+    let mut cave = alloc::phase("parse", || Cave::new(input, true));
+    while cave.drop_sand() {}
+
+    cave.sand_count()
+}
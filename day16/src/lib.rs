@@ -0,0 +1,232 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::collections::{BTreeSet, HashMap};
+
+use common::{
+    alloc,
+    budget::{Budget, Outcome},
+    interner::{Id, Interner},
+    parse::{parse_all, Parser},
+};
+use itertools::Itertools;
+
+struct Valve {
+    id: Id,
+    flow_rate: u64,
+    tunnels: Vec<Id>,
+    paths: Option<HashMap<Id, u64>>,
+}
+
+impl Valve {
+    fn new(id: Id, flow_rate: u64, tunnels: Vec<Id>) -> Self {
+        Self {
+            id,
+            flow_rate,
+            tunnels,
+            paths: None,
+        }
+    }
+
+    /// `pressure` is released by the valves opened before this one, it only
+    /// serves the best-so-far reports of the budget.
+    fn find_max(
+        &self,
+        valves: &HashMap<Id, Valve>,
+        mut minutes_left: u64,
+        opened_valves: &mut Vec<Id>,
+        pressure: u64,
+        budget: &mut Budget,
+    ) -> u64 {
+        if minutes_left == 0 || !budget.step() {
+            return 0;
+        }
+
+        minutes_left -= 1;
+
+        let self_pressure = self.flow_rate * minutes_left;
+        if pressure + self_pressure > budget.best() {
+            budget.set_best(pressure + self_pressure);
+        }
+
+        let mut next_max_max = 0;
+
+        if minutes_left != 0 && valves.len() != opened_valves.len() {
+            opened_valves.push(self.id);
+
+            if let Some(paths) = &self.paths {
+                for (id, dist) in paths {
+                    if !opened_valves.contains(id) && minutes_left > *dist {
+                        let next_max = valves[id].find_max(
+                            valves,
+                            minutes_left - dist,
+                            opened_valves,
+                            pressure + self_pressure,
+                            budget,
+                        );
+
+                        if next_max_max < next_max {
+                            next_max_max = next_max;
+                        }
+                    }
+                }
+            }
+            opened_valves.pop();
+        }
+
+        self_pressure + next_max_max
+    }
+}
+
+fn parse_input(input: &str) -> (Interner, HashMap<Id, Valve>) {
+    let mut names = Interner::new();
+    let mut valves = HashMap::new();
+
+    for line in input.split('\n') {
+        let (name, flow_rate, tunnels) = parse_all(line, |parser: &mut Parser| {
+            parser.literal("Valve ")?;
+            let name = parser.word()?;
+            parser.literal(" has flow rate=")?;
+            let flow_rate = parser.integer::<u64>()?;
+            parser.literal("; tunnel")?;
+            parser.optional("s");
+            parser.literal(" lead")?;
+            parser.optional("s");
+            parser.literal(" to valve")?;
+            parser.optional("s");
+            parser.literal(" ")?;
+            let tunnels = parser.sep_by(", ", |parser| parser.word())?;
+
+            Ok((name, flow_rate, tunnels))
+        })
+        .unwrap_or_else(|e| panic!("Invalid valve: {}", e));
+
+        let id = names.intern(name);
+        let tunnels = tunnels.into_iter().map(|s| names.intern(s)).collect();
+
+        valves.insert(id, Valve::new(id, flow_rate, tunnels));
+    }
+
+    for id in names.ids() {
+        if !valves.contains_key(&id) {
+            panic!("Tunnel leads to unknown valve {}", names.name(id));
+        }
+    }
+
+    let start = start_valve(&names);
+
+    let flow_rates: HashMap<Id, u64> = valves
+        .iter()
+        .map(|(id, valve)| (*id, valve.flow_rate))
+        .collect();
+
+    let mut all_distances = HashMap::new();
+    // Calculate distance to significant valves
+    for (id, _valve) in valves
+        .iter()
+        .filter(|(id, valve)| **id == start || valve.flow_rate != 0)
+    {
+        all_distances.insert(*id, dijkstra(&valves, *id));
+    }
+
+    for (id, valve) in &mut valves {
+        if let Some(paths) = all_distances.get(id) {
+            valve.paths = Some(
+                paths
+                    .iter()
+                    .filter(|(n, _dist)| *n != id && flow_rates[*n] != 0)
+                    .map(|(n, v)| (*n, *v))
+                    .collect(),
+            );
+        }
+    }
+
+    valves.retain(|id, v| *id == start || v.flow_rate != 0);
+
+    (names, valves)
+}
+
+fn start_valve(names: &Interner) -> Id {
+    names.get("AA").expect("Missing start valve AA")
+}
+
+fn dijkstra(valves: &HashMap<Id, Valve>, source: Id) -> HashMap<Id, u64> {
+    // https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm#Pseudocode
+    let mut distances: HashMap<Id, u64> = valves.keys().map(|id| (*id, u64::MAX - 1000)).collect();
+
+    let mut q: BTreeSet<Id> = valves.keys().copied().collect();
+
+    distances.insert(source, 0);
+
+    loop {
+        let min = q.iter().min_by(|a, b| distances[*a].cmp(&distances[*b]));
+
+        if let Some(min_pos) = min {
+            let u = *min_pos;
+            q.remove(&u);
+
+            for v in valves[&u].tunnels.iter().filter(|v| q.contains(*v)) {
+                let alt = distances[&u] + 1;
+                if alt < distances[v] {
+                    distances.insert(*v, alt);
+                }
+            }
+        } else {
+            break;
+        }
+    }
+
+    distances
+}
+
+pub fn part1(input: &str, budget: &mut Budget) -> Outcome<u64> {
+    let (names, valves) = alloc::phase("parse", || parse_input(input));
+    let start = start_valve(&names);
+
+    let mut opened_valves = vec![start];
+    let res = valves[&start].find_max(&valves, 31, &mut opened_valves, 0, budget);
+
+    // The result of an interrupted search misses the branches it cut, the
+    // best pressure reported to the budget is the one to trust
+    if budget.is_exhausted() {
+        return budget.outcome(budget.best());
+    }
+
+    budget.outcome(res)
+}
+
+pub fn part2(input: &str, budget: &mut Budget) -> Outcome<u64> {
+    let (names, valves) = alloc::phase("parse", || parse_input(input));
+    let start = start_valve(&names);
+
+    let valve_ids = valves.keys().copied().collect::<Vec<_>>();
+    let count = valve_ids.len();
+
+    let mut max_res = 0;
+
+    for split in 0..=(count / 2) {
+        for selected_valve_ids in valve_ids.iter().combinations(split) {
+            let mut opened_valves_a = selected_valve_ids.iter().map(|id| **id).collect::<Vec<_>>();
+
+            let res_a = valves[&start].find_max(&valves, 27, &mut opened_valves_a, 0, budget);
+
+            let mut opened_valves_b = valve_ids
+                .iter()
+                .filter(|id| !selected_valve_ids.contains(id))
+                .copied()
+                .collect::<Vec<_>>();
+
+            let res_b = valves[&start].find_max(&valves, 27, &mut opened_valves_b, res_a, budget);
+
+            // Results of an interrupted search are incomplete, the best
+            // pressure reported to the budget covers the cut pair as well
+            if budget.is_exhausted() {
+                return budget.outcome(budget.best());
+            }
+
+            max_res = max_res.max(res_a + res_b);
+        }
+    }
+
+    budget.outcome(max_res)
+}
@@ -0,0 +1,216 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::collections::BTreeSet;
+
+use common::{alloc, cycle};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Shape {
+    Minus,
+    Plus,
+    L,
+    I,
+    Dot,
+}
+
+impl Shape {
+    fn next(&self) -> Self {
+        match self {
+            Shape::Minus => Self::Plus,
+            Shape::Plus => Self::L,
+            Shape::L => Self::I,
+            Shape::I => Self::Dot,
+            Shape::Dot => Self::Minus,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Rock {
+    parts: Vec<(u64, u64)>,
+}
+
+impl Rock {
+    fn new(base: u64, shape: &Shape) -> Self {
+        let bottom = base + 4;
+        Self {
+            parts: match shape {
+                Shape::Minus => vec![(2, bottom), (3, bottom), (4, bottom), (5, bottom)],
+                Shape::Plus => vec![
+                    (2, bottom + 1),
+                    (3, bottom + 1),
+                    (4, bottom + 1),
+                    (3, bottom),
+                    (3, bottom + 2),
+                ],
+                Shape::L => vec![
+                    (2, bottom),
+                    (3, bottom),
+                    (4, bottom),
+                    (4, bottom + 1),
+                    (4, bottom + 2),
+                ],
+                Shape::I => vec![
+                    (2, bottom),
+                    (2, bottom + 1),
+                    (2, bottom + 2),
+                    (2, bottom + 3),
+                ],
+                Shape::Dot => vec![(2, bottom), (3, bottom), (2, bottom + 1), (3, bottom + 1)],
+            },
+        }
+    }
+
+    fn move_sideways(&mut self, direction: i64, used: &BTreeSet<(u64, u64)>) {
+        let limit = if direction < 0 { 0 } else { 6 };
+        if self
+            .parts
+            .iter()
+            .all(|p| p.0 != limit && !used.contains(&((p.0 as i64 + direction) as u64, p.1)))
+        {
+            for part in &mut self.parts {
+                if direction < 0 {
+                    part.0 -= direction.unsigned_abs();
+                } else {
+                    part.0 += direction.unsigned_abs();
+                }
+            }
+        }
+    }
+
+    fn can_fall(&self, used: &BTreeSet<(u64, u64)>) -> bool {
+        self.parts
+            .iter()
+            .all(|part| part.1 > 0 && !used.contains(&(part.0, part.1 - 1)))
+    }
+
+    fn fall(&mut self) {
+        for part in &mut self.parts {
+            part.1 -= 1;
+        }
+    }
+
+    fn update_peaks_and_used(&self, peaks: &mut [u64], used: &mut BTreeSet<(u64, u64)>) {
+        for part in &self.parts {
+            peaks[part.0 as usize] = peaks[part.0 as usize].max(part.1);
+            used.insert(*part);
+        }
+    }
+}
+
+const WIDTH: u64 = 7;
+
+pub struct Chamber {
+    jets: Vec<i64>,
+    next_jet: usize,
+    shape: Shape,
+    peaks: Vec<u64>,
+    used: BTreeSet<(u64, u64)>,
+}
+
+impl Chamber {
+    pub fn new(input: &str) -> Self {
+        let jets = input
+            .chars()
+            .map(|c| match c {
+                '<' => -1,
+                '>' => 1,
+                _ => panic!("Invalid char {}", c),
+            })
+            .collect();
+
+        Self {
+            jets,
+            next_jet: 0,
+            shape: Shape::Minus,
+            peaks: vec![0; WIDTH as usize],
+            used: (0..WIDTH).map(|x| (x, 0)).collect(),
+        }
+    }
+
+    pub fn drop_rock(&mut self) {
+        let mut rock = Rock::new(self.height(), &self.shape);
+
+        loop {
+            let direction = self.jets[self.next_jet];
+            self.next_jet = (self.next_jet + 1) % self.jets.len();
+            rock.move_sideways(direction, &self.used);
+
+            if rock.can_fall(&self.used) {
+                rock.fall();
+            } else {
+                break;
+            }
+        }
+        rock.update_peaks_and_used(&mut self.peaks, &mut self.used);
+        self.shape = self.shape.next();
+    }
+
+    pub fn height(&self) -> u64 {
+        *self.peaks.iter().max().unwrap()
+    }
+
+    /// Summary of the chamber for cycle detection: the next jet and shape with
+    /// the column peaks relative to the top.
+    fn state(&self) -> (usize, Shape, Vec<u64>) {
+        let height = self.height();
+        let skyline = self.peaks.iter().map(|peak| height - peak).collect();
+
+        (self.next_jet, self.shape, skyline)
+    }
+
+    /// Draws the top `rows` rows of the chamber, including the floor if it is
+    /// visible.
+    pub fn draw(&self, rows: u64) -> String {
+        let height = self.height();
+
+        let mut res = String::new();
+        if rows == 0 {
+            return res;
+        }
+
+        for y in (height.saturating_sub(rows - 1).max(1)..=height).rev() {
+            res.push('|');
+            for x in 0..WIDTH {
+                res.push(if self.used.contains(&(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            res.push_str("|\n");
+        }
+
+        if height < rows {
+            res.push_str("+-------+\n");
+        }
+
+        res
+    }
+}
+
+pub fn part1(input: &str, limit: usize) -> u64 {
+    let mut chamber = alloc::phase("parse", || Chamber::new(input));
+
+    for _ in 0..limit {
+        chamber.drop_rock();
+    }
+
+    chamber.height()
+}
+
+pub fn part2(input: &str, count: u64) -> u64 {
+    let mut chamber = alloc::phase("parse", || Chamber::new(input));
+    let mut heights = Vec::new();
+
+    let states = std::iter::from_fn(|| {
+        heights.push(chamber.height() as i64);
+        let state = chamber.state();
+        chamber.drop_rock();
+        Some(state)
+    });
+    let cycle = cycle::find(states).unwrap();
+
+    cycle.extrapolate(&heights, count) as u64
+}
@@ -0,0 +1,264 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::hash::Hash;
+
+use common::{
+    alloc,
+    budget::{Budget, Outcome},
+    memo::{CacheConfig, MemoCache},
+    overflow::{self, CheckedInt, Overflow},
+    parse::fields,
+};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct State {
+    minutes: u16,
+
+    ore_robots: u16,
+    clay_robots: u16,
+    obsidian_robots: u16,
+    geode_robots: u16,
+
+    ore: u16,
+    clay: u16,
+    obsidian: u16,
+    geode: u16,
+}
+
+impl State {
+    fn new(minutes: u16) -> Self {
+        Self {
+            minutes,
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+        }
+    }
+
+    fn mine_minerals(&mut self) {
+        self.ore += self.ore_robots;
+        self.clay += self.clay_robots;
+        self.obsidian += self.obsidian_robots;
+        self.geode += self.geode_robots;
+    }
+}
+
+const BLUEPRINT_PATTERN: &str = "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.";
+
+struct Blueprint {
+    id: u16,
+    ore_robot_ore_cost: u16,
+    clay_robot_ore_cost: u16,
+    obsidian_robot_ore_cost: u16,
+    obsidian_robot_clay_cost: u16,
+    geode_robot_ore_cost: u16,
+    geode_robot_obsidian_cost: u16,
+
+    max_ore_cost: u16,
+}
+
+impl Blueprint {
+    fn new(line: &str) -> Result<Self, Overflow> {
+        let parts =
+            fields(line, BLUEPRINT_PATTERN).unwrap_or_else(|e| panic!("Invalid blueprint: {}", e));
+        let number = |index: usize| {
+            overflow::parse::<u16>(parts[index])
+                .unwrap_or_else(|| panic!("Invalid number {:?} in blueprint", parts[index]))
+        };
+
+        let ore_robot_ore_cost = number(1)?;
+        let clay_robot_ore_cost = number(2)?;
+        let obsidian_robot_ore_cost = number(3)?;
+        let geode_robot_ore_cost = number(5)?;
+
+        Ok(Self {
+            id: number(0)?,
+            ore_robot_ore_cost,
+            clay_robot_ore_cost,
+            obsidian_robot_ore_cost,
+            obsidian_robot_clay_cost: number(4)?,
+            geode_robot_ore_cost,
+            geode_robot_obsidian_cost: number(6)?,
+
+            max_ore_cost: *[
+                ore_robot_ore_cost,
+                clay_robot_ore_cost,
+                obsidian_robot_ore_cost,
+                geode_robot_ore_cost,
+            ]
+            .iter()
+            .max()
+            .unwrap(),
+        })
+    }
+
+    fn step(
+        &self,
+        mut state: State,
+        cache: &mut MemoCache<State, u16>,
+        current_max: &mut u16,
+        budget: &mut Budget,
+    ) -> u16 {
+        if !budget.step() {
+            return 0;
+        }
+
+        if state.minutes == 0 {
+            if state.geode > *current_max {
+                *current_max = state.geode;
+                budget.set_best(*current_max as u64);
+            }
+            return state.geode;
+        }
+
+        let best_possibles_geode_amount = state.minutes * (state.minutes - 1) / 2
+            + state.geode_robots * state.minutes
+            + state.geode;
+
+        if *current_max > best_possibles_geode_amount {
+            return 0;
+        }
+
+        if let Some(cached_value) = cache.get(&state) {
+            return cached_value;
+        }
+
+        state.minutes -= 1;
+
+        let can_build_geode_robot = state.ore >= self.geode_robot_ore_cost
+            && state.obsidian >= self.geode_robot_obsidian_cost;
+        let can_build_obsidian_robot = state.ore >= self.obsidian_robot_ore_cost
+            && state.clay >= self.obsidian_robot_clay_cost
+            && state.obsidian_robots < self.geode_robot_obsidian_cost;
+        let can_build_clay_robot = state.ore >= self.clay_robot_ore_cost
+            && state.clay_robots < self.obsidian_robot_clay_cost;
+        let can_build_ore_robot =
+            state.ore >= self.ore_robot_ore_cost && state.ore_robots < self.max_ore_cost;
+
+        state.mine_minerals();
+
+        // Not building anything
+        let mut max_geodes = self.step(state.clone(), cache, current_max, budget);
+
+        if can_build_geode_robot {
+            // Build geode robot
+            let mut state = state.clone();
+            state.geode_robots += 1;
+            state.ore -= self.geode_robot_ore_cost;
+            state.obsidian -= self.geode_robot_obsidian_cost;
+            max_geodes = max_geodes.max(self.step(state, cache, current_max, budget));
+        }
+
+        if can_build_obsidian_robot {
+            // Build obsidian robot
+            let mut state = state.clone();
+            state.obsidian_robots += 1;
+            state.ore -= self.obsidian_robot_ore_cost;
+            state.clay -= self.obsidian_robot_clay_cost;
+            max_geodes = max_geodes.max(self.step(state, cache, current_max, budget));
+        }
+
+        if can_build_clay_robot {
+            // Build clay robot
+            let mut state = state.clone();
+            state.clay_robots += 1;
+            state.ore -= self.clay_robot_ore_cost;
+            max_geodes = max_geodes.max(self.step(state, cache, current_max, budget));
+        }
+
+        if can_build_ore_robot {
+            // Build ore robot
+            let mut state = state.clone();
+            state.ore_robots += 1;
+            state.ore -= self.ore_robot_ore_cost;
+            max_geodes = max_geodes.max(self.step(state, cache, current_max, budget));
+        }
+
+        // Results of an interrupted search are incomplete
+        if !budget.is_exhausted() {
+            cache.insert(state, max_geodes);
+        }
+
+        max_geodes
+    }
+
+    fn get_max_geodes(
+        &self,
+        minutes: u16,
+        budget: &mut Budget,
+        cache_config: &CacheConfig,
+    ) -> Result<u16, Overflow> {
+        // Robots and minerals grow at most quadratically with the minutes,
+        // which bounds every value of the search
+        if 3 * (minutes as u32 + 1).pow(2) > u16::MAX as u32 {
+            return Err(Overflow::new(format!(
+                "simulating {} minutes (u16)",
+                minutes
+            )));
+        }
+
+        let mut cache = MemoCache::new(cache_config);
+        let state = State::new(minutes);
+        let mut current_max = 0;
+        budget.set_best(0);
+        self.step(state, &mut cache, &mut current_max, budget);
+
+        if cache_config.report {
+            eprintln!("Blueprint {} cache: {}", self.id, cache.stats());
+        }
+
+        // An interrupted search reports the best it has recorded in the budget
+        if budget.is_exhausted() {
+            return Ok(budget.best() as u16);
+        }
+
+        Ok(current_max)
+    }
+
+    fn get_quality_level(
+        &self,
+        minutes: u16,
+        budget: &mut Budget,
+        cache_config: &CacheConfig,
+    ) -> Result<u64, Overflow> {
+        (self.id as u64).try_mul(self.get_max_geodes(minutes, budget, cache_config)? as u64)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Blueprint>, Overflow> {
+    alloc::phase("parse", || input.split('\n').map(Blueprint::new).collect())
+}
+
+pub fn part1(
+    input: &str,
+    budget: &mut Budget,
+    cache_config: &CacheConfig,
+) -> Result<Outcome<u64>, Overflow> {
+    let blueprints = parse_input(input)?;
+    let quality_level_sum = blueprints.iter().try_fold(0u64, |sum, blueprint| {
+        sum.try_add(blueprint.get_quality_level(24, budget, cache_config)?)
+    })?;
+
+    Ok(budget.outcome(quality_level_sum))
+}
+
+pub fn part2(
+    input: &str,
+    budget: &mut Budget,
+    cache_config: &CacheConfig,
+) -> Result<Outcome<Vec<u64>>, Overflow> {
+    let blueprints = parse_input(input)?;
+    let max_geodes = blueprints
+        .iter()
+        .take(3)
+        .map(|blueprint| Ok(blueprint.get_max_geodes(32, budget, cache_config)? as u64))
+        .collect::<Result<_, Overflow>>()?;
+
+    Ok(budget.outcome(max_geodes))
+}
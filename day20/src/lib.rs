@@ -0,0 +1,33 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::{alloc, number::Mod};
+
+pub fn part1(input: &str, multiplier: i64, rounds: usize) -> i64 {
+    let mut nums: Vec<(usize, i64)> = alloc::phase("parse", || {
+        input
+            .split("\n")
+            .enumerate()
+            .map(|(index, line)| (index, line.parse::<i64>().unwrap() * multiplier))
+            .collect()
+    });
+
+    let length = nums.len();
+    for _round in 0..rounds {
+        for i in 0..length {
+            let old_index = nums.iter().position(|(index, _value)| *index == i).unwrap();
+            // The moved item isn't in the list, so it wraps around length - 1
+            let new_index =
+                (Mod::from_u64(old_index as u64, length as u64 - 1) + nums[old_index].1).value();
+
+            let item = nums.remove(old_index);
+            nums.insert(new_index as usize, item);
+        }
+    }
+
+    let zero_pos = nums.iter().position(|a| a.1 == 0).unwrap();
+
+    nums[(zero_pos + 1000) % length].1
+        + nums[(zero_pos + 2000) % length].1
+        + nums[(zero_pos + 3000) % length].1
+}
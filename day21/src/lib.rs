@@ -0,0 +1,178 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::{
+    alloc,
+    interner::{Id, Interner},
+    overflow::{self, CheckedInt, Overflow},
+};
+
+#[derive(Debug, Clone)]
+struct Operation {
+    op1: Id,
+    op2: Id,
+    op_type: String,
+}
+
+impl Operation {
+    fn new(value: &str, names: &Interner) -> Self {
+        let parts: Vec<&str> = value.split(' ').collect();
+        let id_of = |name: &str| {
+            names
+                .get(name)
+                .unwrap_or_else(|| panic!("Unknown monkey {}", name))
+        };
+
+        Self {
+            op1: id_of(parts[0]),
+            op2: id_of(parts[2]),
+            op_type: String::from(parts[1]),
+        }
+    }
+
+    fn get_value(&self, monkeys: &[Monkey]) -> Result<i64, Overflow> {
+        let op1 = monkeys[self.op1.index()].get_value(monkeys)?;
+        let op2 = monkeys[self.op2.index()].get_value(monkeys)?;
+
+        match self.op_type.as_str() {
+            "+" => op1.try_add(op2),
+            "-" => op1.try_sub(op2),
+            "*" => op1.try_mul(op2),
+            "/" => op1.try_div(op2),
+            op => panic!("Invalid operation {}", op),
+        }
+    }
+
+    fn is_unknown(&self, monkeys: &[Monkey]) -> bool {
+        let op1 = &monkeys[self.op1.index()];
+        let op2 = &monkeys[self.op2.index()];
+
+        op1.is_unknown(monkeys) || op2.is_unknown(monkeys)
+    }
+
+    fn solve_unknown(&self, monkeys: &[Monkey], res: i64) -> Result<i64, Overflow> {
+        let op1 = &monkeys[self.op1.index()];
+        let op2 = &monkeys[self.op2.index()];
+
+        if op1.is_unknown(monkeys) {
+            let next_res = match self.op_type.as_str() {
+                "+" => res.try_sub(op2.get_value(monkeys)?),
+                "-" => res.try_add(op2.get_value(monkeys)?),
+                "*" => res.try_div(op2.get_value(monkeys)?),
+                "/" => res.try_mul(op2.get_value(monkeys)?),
+                "=" => op2.get_value(monkeys),
+                op => panic!("Invalid operation {}", op),
+            };
+
+            op1.solve_unknowns(monkeys, next_res?)
+        } else {
+            let next_res = match self.op_type.as_str() {
+                "+" => res.try_sub(op1.get_value(monkeys)?),
+                "-" => op1.get_value(monkeys)?.try_sub(res),
+                "*" => res.try_div(op1.get_value(monkeys)?),
+                "/" => op1.get_value(monkeys)?.try_div(res),
+                "=" => op1.get_value(monkeys),
+                op => panic!("Invalid operation {}", op),
+            };
+            op2.solve_unknowns(monkeys, next_res?)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    value: Option<i64>,
+    operation: Option<Operation>,
+    unknown: bool,
+}
+
+impl Monkey {
+    fn new(value: &str, names: &Interner) -> Result<Self, Overflow> {
+        let value_as_option_int = overflow::parse(value).transpose()?;
+
+        let operation = if value_as_option_int.is_none() {
+            Some(Operation::new(value, names))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            value: value_as_option_int,
+            operation,
+            unknown: false,
+        })
+    }
+
+    fn get_value(&self, monkeys: &[Monkey]) -> Result<i64, Overflow> {
+        if let Some(value) = self.value {
+            Ok(value)
+        } else {
+            self.operation.as_ref().unwrap().get_value(monkeys)
+        }
+    }
+
+    fn is_unknown(&self, monkeys: &[Monkey]) -> bool {
+        self.unknown
+            || (self.value.is_none() && self.operation.as_ref().unwrap().is_unknown(monkeys))
+    }
+
+    fn solve_unknowns(&self, monkeys: &[Monkey], res: i64) -> Result<i64, Overflow> {
+        if self.unknown {
+            Ok(res)
+        } else {
+            self.operation.as_ref().unwrap().solve_unknown(monkeys, res)
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Interner, Vec<Monkey>), Overflow> {
+    let lines: Vec<(&str, &str)> = input
+        .split('\n')
+        .map(|line| {
+            line.split_once(": ")
+                .unwrap_or_else(|| panic!("Invalid monkey {:?}", line))
+        })
+        .collect();
+
+    // Monkeys are interned in input order so their ids index the monkey list
+    let mut names = Interner::new();
+    for (name, _job) in &lines {
+        names.intern(name);
+    }
+
+    let monkeys = lines
+        .iter()
+        .map(|(_name, job)| Monkey::new(job, &names))
+        .collect::<Result<_, _>>()?;
+
+    Ok((names, monkeys))
+}
+
+fn monkey_id(names: &Interner, name: &str) -> Id {
+    names
+        .get(name)
+        .unwrap_or_else(|| panic!("Missing monkey {}", name))
+}
+
+pub fn part1(input: &str) -> Result<i64, Overflow> {
+    let (names, monkeys) = alloc::phase("parse", || parse_input(input))?;
+
+    monkeys[monkey_id(&names, "root").index()].get_value(&monkeys)
+}
+
+pub fn part2(input: &str) -> Result<i64, Overflow> {
+    let (names, mut monkeys) = alloc::phase("parse", || parse_input(input))?;
+    let root = monkey_id(&names, "root").index();
+    let humn = monkey_id(&names, "humn").index();
+
+    monkeys[root]
+        .operation
+        .as_mut()
+        .unwrap()
+        .op_type
+        .replace_range(0..1, "=");
+    monkeys[humn].unknown = true;
+    monkeys[humn].value = None;
+
+    monkeys[root].solve_unknowns(&monkeys, 0)
+}
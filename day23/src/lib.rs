@@ -0,0 +1,144 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use common::alloc;
+
+const OFFSETS: [[(i64, i64); 3]; 4] = [
+    [(-1, -1), (0, -1), (1, -1)],
+    [(-1, 1), (0, 1), (1, 1)],
+    [(-1, -1), (-1, 0), (-1, 1)],
+    [(1, -1), (1, 0), (1, 1)],
+];
+
+pub struct Grove {
+    elves: BTreeSet<(i64, i64)>,
+    round: usize,
+}
+
+impl Grove {
+    pub fn new(input: &str) -> Self {
+        let mut elves = BTreeSet::new();
+
+        for (y, line) in input.split('\n').enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c == '#' {
+                    elves.insert((x as i64, y as i64));
+                }
+            }
+        }
+
+        Self { elves, round: 0 }
+    }
+
+    /// Number of rounds in which elves have moved.
+    pub fn rounds(&self) -> usize {
+        self.round
+    }
+
+    fn bounds(&self) -> ((i64, i64), (i64, i64)) {
+        let min_x = *self.elves.iter().map(|(x, _y)| x).min().unwrap();
+        let max_x = *self.elves.iter().map(|(x, _y)| x).max().unwrap();
+        let min_y = *self.elves.iter().map(|(_x, y)| y).min().unwrap();
+        let max_y = *self.elves.iter().map(|(_x, y)| y).max().unwrap();
+
+        ((min_x, min_y), (max_x, max_y))
+    }
+
+    /// Empty ground tiles in the smallest rectangle containing every elf.
+    pub fn empty_ground(&self) -> usize {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds();
+
+        ((max_x - min_x + 1) * (max_y - min_y + 1)) as usize - self.elves.len()
+    }
+
+    /// Runs a round of the spreading process. Returns false if no elf needed
+    /// to move.
+    pub fn round(&mut self) -> bool {
+        let elves = &self.elves;
+        let mut new_positions = BTreeMap::new();
+
+        // Check new positions
+        for elf in elves {
+            let no_need_to_move = OFFSETS
+                .iter()
+                .flatten()
+                .all(|d| !elves.contains(&(elf.0 + d.0, elf.1 + d.1)));
+            if no_need_to_move {
+                continue;
+            }
+
+            for dir in OFFSETS.iter().cycle().skip(self.round).take(4) {
+                if dir
+                    .iter()
+                    .all(|d| !elves.contains(&(elf.0 + d.0, elf.1 + d.1)))
+                {
+                    new_positions.insert(elf, (elf.0 + dir[1].0, elf.1 + dir[1].1));
+                    break;
+                }
+            }
+        }
+
+        if new_positions.is_empty() {
+            return false;
+        }
+
+        // Move elves
+        let mut new_elves = BTreeSet::new();
+        for elf in elves {
+            if let Some(new_pos) = new_positions.get(&elf) {
+                if new_positions.iter().filter(|p| *p.1 == *new_pos).count() <= 1 {
+                    // Able to move
+                    new_elves.insert(*new_pos);
+                } else {
+                    // Conflict in move
+                    new_elves.insert(*elf);
+                }
+            } else {
+                // Couldn't move at the beginning
+                new_elves.insert(*elf);
+            }
+        }
+
+        self.elves = new_elves;
+        self.round += 1;
+
+        true
+    }
+
+    pub fn draw(&self) -> String {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds();
+
+        let mut res = String::new();
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                res.push(if self.elves.contains(&(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            res.push('\n');
+        }
+
+        res
+    }
+}
+
+pub fn part12(input: &str) -> (usize, usize) {
+    let mut grove = alloc::phase("parse", || Grove::new(input));
+
+    let mut round_10_res = 0;
+    loop {
+        if grove.rounds() == 10 {
+            round_10_res = grove.empty_ground();
+        }
+
+        if !grove.round() {
+            break;
+        }
+    }
+
+    (round_10_res, grove.rounds() + 1)
+}
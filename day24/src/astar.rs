@@ -39,13 +39,13 @@ impl<P> Eq for MinWrapper<P> {}
 
 impl<P> PartialOrd for MinWrapper<P> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        other.weight.partial_cmp(&self.weight)
+        Some(self.cmp(other))
     }
 }
 
 impl<P> Ord for MinWrapper<P> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
+        other.weight.cmp(&self.weight)
     }
 }
 
@@ -72,7 +72,7 @@ pub fn a_star<
     is_goal: OK,
 ) -> Vec<P> {
     let mut open_set = BinaryHeap::new();
-    open_set.push(MinWrapper::new(start.clone(), heuristic_func(&start)));
+    open_set.push(MinWrapper::new(start, heuristic_func(&start)));
 
     let mut came_from = HashMap::new();
 
@@ -93,7 +93,7 @@ pub fn a_star<
                 .unwrap_or(&usize::MAX);
 
             if tentative_score < best_neighbor_dist_from_start {
-                came_from.insert(neighbor.clone(), *current);
+                came_from.insert(neighbor, *current);
                 best_distance_from_start.insert(neighbor, tentative_score);
                 open_set.push(MinWrapper {
                     value: neighbor,
@@ -103,5 +103,5 @@ pub fn a_star<
         }
     }
 
-    return Vec::new();
+    Vec::new()
 }
@@ -0,0 +1,233 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::hash::Hash;
+
+use common::{alloc, number};
+
+use crate::astar::a_star;
+
+mod astar;
+
+#[derive(Clone)]
+struct Blizzard {
+    position: (usize, usize),
+    direction: char,
+}
+
+impl Blizzard {
+    fn step(&mut self, width: usize, height: usize) {
+        match self.direction {
+            '>' => {
+                if self.position.0 < width - 2 {
+                    self.position.0 += 1;
+                } else {
+                    self.position.0 = 1;
+                }
+            }
+            '<' => {
+                if self.position.0 > 1 {
+                    self.position.0 -= 1;
+                } else {
+                    self.position.0 = width - 2;
+                }
+            }
+            'v' => {
+                if self.position.1 < height - 2 {
+                    self.position.1 += 1;
+                } else {
+                    self.position.1 = 1;
+                }
+            }
+            '^' => {
+                if self.position.1 > 1 {
+                    self.position.1 -= 1;
+                } else {
+                    self.position.1 = height - 2;
+                }
+            }
+            d => panic!("Invalid blizzard direction {}", d),
+        }
+    }
+
+    fn is_at_position(&self, pos: &Elves) -> bool {
+        self.position == (pos.x, pos.y)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Elves {
+    x: usize,
+    y: usize,
+    time: usize,
+}
+
+impl Elves {
+    fn new(x: usize, y: usize, time: usize) -> Self {
+        Self { x, y, time }
+    }
+
+    fn manchester_distance(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    fn is_in_area(&self, width: usize, height: usize) -> bool {
+        if 1 <= self.x && self.x < width - 1 && 1 <= self.y && self.y < height - 1 {
+            return true;
+        }
+
+        if (self.x == 1 && self.y == 0) || (self.x == width - 2 && self.y == height - 1) {
+            return true;
+        }
+
+        false
+    }
+
+    fn get_neighbors(&self) -> Vec<Self> {
+        vec![
+            Self::new(self.x, self.y, self.time + 1),
+            Self::new(self.x + 1, self.y, self.time + 1),
+            Self::new(self.x - 1, self.y, self.time + 1),
+            Self::new(self.x, self.y + 1, self.time + 1),
+            Self::new(self.x, self.y.overflowing_sub(1).0, self.time + 1), // Handle starting point with overflow
+        ]
+    }
+}
+
+#[derive(Clone)]
+pub struct Valley {
+    blizzards: Vec<Blizzard>,
+    width: usize,
+    height: usize,
+}
+
+impl Valley {
+    pub fn new(input: &str) -> Self {
+        let mut blizzards: Vec<Blizzard> = Vec::new();
+
+        let mut width = 0;
+        let mut height = 0;
+        for (y, line) in input.split("\n").enumerate() {
+            width = line.len();
+            height = y + 1;
+
+            for (x, c) in line.chars().enumerate() {
+                if c != '#' && c != '.' {
+                    blizzards.push(Blizzard {
+                        position: (x, y),
+                        direction: c,
+                    });
+                }
+            }
+        }
+
+        Self {
+            blizzards,
+            width,
+            height,
+        }
+    }
+
+    /// Moves every blizzard by one minute.
+    pub fn step(&mut self) {
+        for blizzard in self.blizzards.iter_mut() {
+            blizzard.step(self.width, self.height);
+        }
+    }
+
+    /// Draws the valley like the puzzle description, with the count of the
+    /// blizzards on tiles where more of them meet.
+    pub fn draw(&self) -> String {
+        self.draw_expedition(None)
+    }
+
+    /// Draws the valley with the expedition marked as `E`.
+    pub fn draw_expedition(&self, expedition: Option<(usize, usize)>) -> String {
+        let mut tiles = vec![vec![0usize; self.width]; self.height];
+        let mut directions = vec![vec!['.'; self.width]; self.height];
+        for blizzard in &self.blizzards {
+            let (x, y) = blizzard.position;
+            tiles[y][x] += 1;
+            directions[y][x] = blizzard.direction;
+        }
+
+        let mut res = String::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let is_wall = x == 0 || x == self.width - 1 || y == 0 || y == self.height - 1;
+                let is_opening =
+                    (x == 1 && y == 0) || (x == self.width - 2 && y == self.height - 1);
+
+                res.push(match tiles[y][x] {
+                    _ if expedition == Some((x, y)) => 'E',
+                    _ if is_wall && !is_opening => '#',
+                    0 => '.',
+                    1 => directions[y][x],
+                    n => char::from_digit(n as u32, 10).unwrap_or('*'),
+                });
+            }
+            res.push('\n');
+        }
+
+        res
+    }
+}
+
+/// Positions of the expedition minute by minute, from the entrance until
+/// reaching the goal.
+pub fn expedition(input: &str, is_part2: bool) -> Vec<(usize, usize)> {
+    let valley = alloc::phase("parse", || Valley::new(input));
+    let (width, height) = (valley.width, valley.height);
+
+    // Each blizzard wraps around the area inside the walls, so all of them
+    // are back at their starting positions after this many minutes
+    let size = number::lcm(width as u64 - 2, height as u64 - 2) as usize;
+
+    let mut blizzard_maps: Vec<Vec<Blizzard>> = Vec::new();
+    let mut blizzards = valley.blizzards;
+    for _i in 0..size {
+        blizzard_maps.push(blizzards.clone());
+        for blizzard in blizzards.iter_mut() {
+            blizzard.step(width, height);
+        }
+    }
+
+    let neighbors = |elves: &Elves| {
+        let next_blizzards = &blizzard_maps[(elves.time + 1) % size];
+
+        elves
+            .get_neighbors()
+            .into_iter()
+            .filter(|p| {
+                p.is_in_area(width, height) && !next_blizzards.iter().any(|b| b.is_at_position(p))
+            })
+            .collect()
+    };
+    let distance_func = |a: &Elves, b: &Elves| a.time.abs_diff(b.time);
+    let is_goal = |a: &Elves, b: &Elves| a.x == b.x && a.y == b.y;
+
+    let start = Elves::new(1, 0, 0);
+    let goal = Elves::new(width - 2, height - 1, 0);
+    let heuristic = |p: &Elves| goal.manchester_distance(p);
+    let mut path = a_star(start, goal, heuristic, distance_func, neighbors, is_goal);
+
+    if is_part2 {
+        let start = Elves::new(width - 2, height - 1, path.len() - 1);
+        let goal = Elves::new(1, 0, 0);
+        let heuristic = |p: &Elves| goal.manchester_distance(p);
+        let path2 = a_star(start, goal, heuristic, distance_func, neighbors, is_goal);
+        path.extend_from_slice(&path2[1..]);
+
+        let start = Elves::new(1, 0, path.len() - 1);
+        let goal = Elves::new(width - 2, height - 1, 0);
+        let heuristic = |p: &Elves| goal.manchester_distance(p);
+        let path3 = a_star(start, goal, heuristic, distance_func, neighbors, is_goal);
+        path.extend_from_slice(&path3[1..]);
+    }
+
+    path.iter().map(|elves| (elves.x, elves.y)).collect()
+}
+
+pub fn part12(input: &str, is_part2: bool) -> usize {
+    expedition(input, is_part2).len() - 1
+}
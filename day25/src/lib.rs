@@ -0,0 +1,61 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::{
+    alloc,
+    overflow::{CheckedInt, Overflow},
+};
+
+pub fn snafu_to_number(snafu: &str) -> Result<i64, Overflow> {
+    let mut res: i64 = 0;
+    for c in snafu.chars() {
+        let digit = match c {
+            '2' => 2,
+            '1' => 1,
+            '0' => 0,
+            '-' => -1,
+            '=' => -2,
+            c => panic!("MI {}", c),
+        };
+
+        res = res.try_mul(5)?.try_add(digit)?;
+    }
+
+    Ok(res)
+}
+
+pub fn number_to_snafu(mut x: i64) -> String {
+    let mut res = Vec::new();
+    while x != 0 {
+        let rem = x % 5;
+        x /= 5;
+
+        let (carry, s) = match rem {
+            0 => (0, '0'),
+            1 => (0, '1'),
+            2 => (0, '2'),
+            3 => (1, '='),
+            4 => (1, '-'),
+            5 => (1, '0'),
+            _ => panic!("FOS"),
+        };
+
+        x += carry;
+        res.push(s)
+    }
+
+    res.iter().rev().collect()
+}
+
+pub fn part1(input: &str) -> Result<i64, Overflow> {
+    let numbers = alloc::phase("parse", || {
+        input
+            .split("\n")
+            .map(snafu_to_number)
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+
+    numbers
+        .into_iter()
+        .try_fold(0i64, |sum, number| sum.try_add(number))
+}
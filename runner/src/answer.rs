@@ -0,0 +1,94 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Display;
+
+use common::{json, overflow::Overflow};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    Lines(Vec<String>),
+    /// The solver couldn't compute the answer
+    Error(String),
+}
+
+impl Answer {
+    pub fn to_json(&self) -> String {
+        match self {
+            Answer::Number(value) => format!("{{\"type\":\"number\",\"value\":{}}}", value),
+            Answer::Text(value) => {
+                format!("{{\"type\":\"text\",\"value\":{}}}", json::string(value))
+            }
+            Answer::Lines(lines) => format!(
+                "{{\"type\":\"lines\",\"value\":[{}]}}",
+                lines
+                    .iter()
+                    .map(|line| json::string(line))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            Answer::Error(message) => {
+                format!("{{\"type\":\"error\",\"value\":{}}}", json::string(message))
+            }
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Lines(lines) => {
+                for line in lines {
+                    write!(f, "\n{}", line)?;
+                }
+                Ok(())
+            }
+            Answer::Error(message) => write!(f, "error: {}", message),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => Answer::Number(value),
+                        Err(_) => Answer::Error(
+                            Overflow::new(format!("converting {} ({}) to i64", value, stringify!($t)))
+                                .to_string(),
+                        ),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(value: Vec<String>) -> Self {
+        Answer::Lines(value)
+    }
+}
+
+impl<T: Into<Answer>, E: Display> From<Result<T, E>> for Answer {
+    fn from(value: Result<T, E>) -> Self {
+        match value {
+            Ok(value) => value.into(),
+            Err(e) => Answer::Error(e.to_string()),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::path::Path;
+
+use common::snapshot::accept_pending;
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    let mut count = 0;
+    for day in 1..=25 {
+        let snapshot_dir = root.join(format!("day{:02}", day)).join("snapshots");
+        for path in accept_pending(&snapshot_dir) {
+            println!("Accepted {}", path.display());
+            count += 1;
+        }
+    }
+
+    println!("{} snapshots accepted", count);
+}
@@ -0,0 +1,71 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::path::Path;
+
+use runner::puzzle::parse_page;
+
+const USAGE: &str = "Usage: extract_examples <day> <page.html> [--block <n>] [--force]";
+
+fn write(path: &Path, content: &str, force: bool) {
+    if path.exists() && !force {
+        panic!(
+            "{} already exists, use --force to overwrite",
+            path.display()
+        );
+    }
+
+    std::fs::write(path, content)
+        .unwrap_or_else(|e| panic!("Failed to write {}: {}", path.display(), e));
+    println!("Wrote {}", path.display());
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let day: u8 = args
+        .first()
+        .and_then(|day| day.parse().ok())
+        .unwrap_or_else(|| panic!("{}", USAGE));
+    let page = args.get(1).unwrap_or_else(|| panic!("{}", USAGE));
+    let block = args
+        .iter()
+        .position(|arg| arg == "--block")
+        .and_then(|index| args.get(index + 1))
+        .map(|block| block.parse::<usize>().expect("Invalid block"))
+        .unwrap_or(0);
+    let force = args.iter().any(|arg| arg == "--force");
+
+    let html = std::fs::read_to_string(page)
+        .unwrap_or_else(|e| panic!("Failed to read page {}: {}", page, e));
+    let puzzle = parse_page(&html);
+
+    for (index, example) in puzzle.examples.iter().enumerate() {
+        println!(
+            "Block {}: {} lines, starting with {:?}",
+            index,
+            example.lines().count(),
+            example.lines().next().unwrap_or("")
+        );
+    }
+    for (index, answer) in puzzle.answers.iter().enumerate() {
+        println!("Part {} answer: {}", index + 1, answer);
+    }
+
+    let example = puzzle
+        .examples
+        .get(block)
+        .unwrap_or_else(|| panic!("No example block {} on the page", block));
+
+    let src = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{:02}", day))
+        .join("src");
+    write(&src.join("input_test"), example, force);
+    // Same layout as the answers of the real input, one line per part
+    write(
+        &src.join("answers_test"),
+        &(puzzle.answers.join("\n") + "\n"),
+        force,
+    );
+}
@@ -0,0 +1,25 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use runner::leaderboard::Leaderboard;
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let path = args
+        .iter()
+        .find(|arg| !arg.starts_with("--"))
+        .unwrap_or_else(|| panic!("Usage: leaderboard <export.json> [--json]"));
+    let json = args.iter().any(|arg| arg == "--json");
+
+    let text = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Failed to read leaderboard {}: {}", path, e));
+    let leaderboard = Leaderboard::from_json(&text)
+        .unwrap_or_else(|e| panic!("Invalid leaderboard {}: {}", path, e));
+
+    if json {
+        println!("{}", leaderboard.to_json());
+    } else {
+        print!("{}", leaderboard.to_text());
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    time::Duration,
+};
+
+use common::budget::{Budget, Outcome};
+use runner::{
+    answer::Answer,
+    registry::{load_input, parts, Solution, Solver},
+    report::{expected_answers, html, visualizations, DayReport},
+};
+
+const DEFAULT_OUTPUT: &str = "report.html";
+
+/// A panicking solver fails its own row instead of the whole report.
+fn run(solver: &Solver, input: &str) -> Solution {
+    catch_unwind(AssertUnwindSafe(|| {
+        solver.run(input, &mut Budget::from_args())
+    }))
+    .unwrap_or_else(|_| Solution {
+        day: solver.day,
+        part: solver.part,
+        outcome: Outcome::Finished(Answer::Error("solver panicked".to_string())),
+        elapsed: Duration::ZERO,
+        memory: None,
+        phases: Vec::new(),
+    })
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let output = args
+        .iter()
+        .position(|arg| arg == "--output")
+        .and_then(|index| args.get(index + 1))
+        .map_or(DEFAULT_OUTPUT, |output| output.as_str());
+
+    let mut days = Vec::new();
+    for day in 1..=25 {
+        eprintln!("Day {:02}", day);
+
+        let input = load_input(day);
+
+        days.push(DayReport {
+            day,
+            solutions: parts(day).map(|solver| run(solver, &input)).collect(),
+            expected: expected_answers(day),
+            visualizations: catch_unwind(|| visualizations(day, &input)).unwrap_or_default(),
+        });
+    }
+
+    std::fs::write(output, html(&days))
+        .unwrap_or_else(|e| panic!("Failed to write report {}: {}", output, e));
+    println!("Report written to {}", output);
+}
@@ -0,0 +1,175 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{Ipv4Addr, TcpListener, TcpStream},
+    panic::{catch_unwind, AssertUnwindSafe},
+    time::Duration,
+};
+
+use common::budget::Budget;
+use runner::registry::{error_json, find};
+
+const DEFAULT_PORT: u16 = 8022;
+const MAX_BODY_SIZE: usize = 16 * 1024 * 1024;
+// A stalled client would hold its thread forever otherwise
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+struct Request {
+    method: String,
+    path: String,
+    query: String,
+    body: Vec<u8>,
+}
+
+fn read_request(stream: &mut TcpStream) -> Result<Request, String> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader
+        .read_line(&mut request_line)
+        .map_err(|e| e.to_string())?;
+    let parts: Vec<&str> = request_line.split_whitespace().collect();
+    if parts.len() != 3 {
+        return Err(format!("Invalid request line {:?}", request_line));
+    }
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).map_err(|e| e.to_string())?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("Invalid content length {:?}", value))?;
+            }
+        }
+    }
+
+    if content_length > MAX_BODY_SIZE {
+        return Err(format!("Body exceeds {} bytes", MAX_BODY_SIZE));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(|e| e.to_string())?;
+
+    let (path, query) = parts[1].split_once('?').unwrap_or((parts[1], ""));
+
+    Ok(Request {
+        method: parts[0].to_string(),
+        path: path.to_string(),
+        query: query.to_string(),
+        body,
+    })
+}
+
+fn write_response(stream: &mut TcpStream, status: &str, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+
+    // The client might have gone away, nothing to do about it
+    let _ = stream.write_all(response.as_bytes());
+}
+
+fn budget_from_query(query: &str) -> Result<Budget, String> {
+    let mut budget = Budget::unlimited();
+
+    for (name, value) in query.split('&').filter_map(|p| p.split_once('=')) {
+        let value = value
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid value for {}: {}", name, value))?;
+
+        budget = match name {
+            "time_limit" => budget.with_time_limit(Duration::from_secs(value)),
+            "step_limit" => budget.with_step_limit(value),
+            _ => return Err(format!("Unknown parameter {}", name)),
+        };
+    }
+
+    Ok(budget)
+}
+
+// POST /solve/{day}/{part}
+fn handle(request: Request) -> (&'static str, String) {
+    let route: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+
+    let (day, part) = match route[..] {
+        ["solve", day, part] => match (day.parse::<u8>(), part.parse::<u8>()) {
+            (Ok(day), Ok(part)) => (day, part),
+            _ => return ("404 Not Found", error_json("Invalid day or part")),
+        },
+        _ => return ("404 Not Found", error_json("Unknown endpoint")),
+    };
+
+    if request.method != "POST" {
+        return ("405 Method Not Allowed", error_json("Use POST"));
+    }
+
+    let solver = match find(day, part) {
+        Some(solver) => solver,
+        None => return ("404 Not Found", error_json("No solver for this puzzle")),
+    };
+
+    let mut budget = match budget_from_query(&request.query) {
+        Ok(budget) => budget,
+        Err(message) => return ("400 Bad Request", error_json(&message)),
+    };
+
+    let input = match String::from_utf8(request.body) {
+        Ok(input) => input,
+        Err(_) => return ("400 Bad Request", error_json("Input is not UTF-8")),
+    };
+
+    match catch_unwind(AssertUnwindSafe(|| solver.run(&input, &mut budget))) {
+        Ok(solution) => ("200 OK", solution.to_json()),
+        Err(_) => (
+            "422 Unprocessable Entity",
+            error_json("Solver failed on this input"),
+        ),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let port = args
+        .iter()
+        .position(|arg| arg == "--port")
+        .and_then(|index| args.get(index + 1))
+        .map(|port| port.parse().expect("Invalid port"))
+        .unwrap_or(DEFAULT_PORT);
+
+    // Only local tools are served
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .unwrap_or_else(|e| panic!("Failed to listen on port {}: {}", port, e));
+    println!("Listening on http://{}", listener.local_addr().unwrap());
+
+    for stream in listener.incoming() {
+        let mut stream = match stream {
+            Ok(stream) => stream,
+            Err(_) => continue,
+        };
+        if stream.set_read_timeout(Some(READ_TIMEOUT)).is_err() {
+            continue;
+        }
+
+        std::thread::spawn(move || {
+            let (status, body) = match read_request(&mut stream) {
+                Ok(request) => handle(request),
+                Err(message) => ("400 Bad Request", error_json(&message)),
+            };
+            write_response(&mut stream, status, &body);
+        });
+    }
+}
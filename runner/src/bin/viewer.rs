@@ -0,0 +1,202 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{io::Write, sync::mpsc::RecvTimeoutError, time::Duration};
+
+use runner::{
+    registry::load_input,
+    simulation::{simulation, Simulation},
+    terminal::{keys, Key, RawMode, TerminalSize},
+};
+
+const USAGE: &str = "Usage: viewer <day> [part] [--input <file>] [--delay <ms>]";
+const HELP: &str =
+    "space: pause  n: step  +/-: speed  arrows/hjkl/PgUp/PgDn: scroll  f: follow  q: quit";
+
+const MIN_DELAY: Duration = Duration::from_millis(1);
+const MAX_DELAY: Duration = Duration::from_secs(2);
+
+struct Viewer {
+    simulation: Box<dyn Simulation>,
+    paused: bool,
+    finished: bool,
+    delay: Duration,
+    row: usize,
+    column: usize,
+    // Scrolls to the rows which changed in the last step
+    follow: bool,
+    previous_frame: String,
+    size: TerminalSize,
+}
+
+impl Viewer {
+    fn step(&mut self) {
+        if self.finished {
+            return;
+        }
+
+        let rows = self.frame_rows();
+        self.previous_frame = self.simulation.frame(rows);
+        self.finished = !self.simulation.step();
+    }
+
+    /// Rows of the frame down to the bottom of the screen.
+    fn frame_rows(&mut self) -> usize {
+        self.row + self.size.get().0.saturating_sub(2).max(1)
+    }
+
+    fn render(&mut self) {
+        let (rows, columns) = self.size.get();
+        let visible_rows = rows.saturating_sub(2).max(1);
+
+        let frame_rows = self.frame_rows();
+        let frame = self.simulation.frame(frame_rows);
+        let lines: Vec<&str> = frame.lines().collect();
+
+        if self.follow {
+            let previous: Vec<&str> = self.previous_frame.lines().collect();
+            // Frames may grow at the top, compare them aligned at the bottom
+            let changed = (0..lines.len()).find(|&index| {
+                let offset = lines.len() - index;
+                previous.len() < offset || previous[previous.len() - offset] != lines[index]
+            });
+            if let Some(changed) = changed {
+                if changed < self.row || changed >= self.row + visible_rows {
+                    self.row = changed.saturating_sub(visible_rows / 2);
+                }
+            }
+        }
+        self.row = self.row.min(lines.len().saturating_sub(visible_rows));
+
+        let mut screen = String::from("\x1b[H\x1b[2J");
+        for line in lines.iter().skip(self.row).take(visible_rows) {
+            let visible: String = line.chars().skip(self.column).take(columns).collect();
+            screen.push_str(&visible);
+            screen.push_str("\r\n");
+        }
+
+        let state = if self.finished {
+            "finished"
+        } else if self.paused {
+            "paused"
+        } else {
+            "running"
+        };
+        screen.push_str(&format!(
+            "\x1b[{};1H\x1b[7m{} | {} | {:?} per step | row {}, column {}\x1b[0m\r\n{}",
+            rows - 1,
+            self.simulation.status(),
+            state,
+            self.delay,
+            self.row,
+            self.column,
+            HELP.chars().take(columns).collect::<String>()
+        ));
+
+        print!("{}", screen);
+        let _ = std::io::stdout().flush();
+    }
+
+    /// Returns false when the viewer should quit.
+    fn handle(&mut self, key: Key) -> bool {
+        let page = self.size.get().0.saturating_sub(2).max(1);
+
+        match key {
+            Key::Char('q') | Key::Char('\x03') => return false,
+            Key::Char(' ') => self.paused = !self.paused,
+            Key::Char('n') | Key::Char('.') => {
+                self.paused = true;
+                self.step();
+            }
+            Key::Char('+') | Key::Char('=') => self.delay = (self.delay / 2).max(MIN_DELAY),
+            Key::Char('-') => self.delay = (self.delay * 2).min(MAX_DELAY),
+            Key::Char('f') => self.follow = !self.follow,
+            Key::Up | Key::Char('k') => {
+                self.follow = false;
+                self.row = self.row.saturating_sub(1);
+            }
+            Key::Down | Key::Char('j') => {
+                self.follow = false;
+                self.row += 1;
+            }
+            Key::PageUp => {
+                self.follow = false;
+                self.row = self.row.saturating_sub(page);
+            }
+            Key::PageDown => {
+                self.follow = false;
+                self.row += page;
+            }
+            Key::Left | Key::Char('h') => self.column = self.column.saturating_sub(1),
+            Key::Right | Key::Char('l') => self.column += 1,
+            _ => {}
+        }
+
+        true
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let value_of = |name: &str| {
+        args.iter()
+            .position(|arg| arg == name)
+            .and_then(|index| args.get(index + 1))
+    };
+
+    let day: u8 = args
+        .first()
+        .and_then(|day| day.parse().ok())
+        .unwrap_or_else(|| panic!("{}", USAGE));
+    let part: u8 = args.get(1).and_then(|part| part.parse().ok()).unwrap_or(1);
+    let delay = value_of("--delay")
+        .map(|delay| delay.parse().expect("Invalid delay"))
+        .map(Duration::from_millis)
+        .unwrap_or(Duration::from_millis(100));
+
+    let input = match value_of("--input") {
+        Some(path) => std::fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("Failed to read input {}: {}", path, e)),
+        None => load_input(day),
+    };
+
+    let simulation = simulation(day, part, &input)
+        .unwrap_or_else(|| panic!("Day {} has no simulation to view", day));
+
+    let mut viewer = Viewer {
+        previous_frame: String::new(),
+        simulation,
+        paused: false,
+        finished: false,
+        delay,
+        row: 0,
+        column: 0,
+        follow: true,
+        size: TerminalSize::query(),
+    };
+    let rows = viewer.frame_rows();
+    viewer.previous_frame = viewer.simulation.frame(rows);
+
+    let keys = keys();
+    let _raw_mode = RawMode::enable().expect("Failed to set up the terminal");
+
+    loop {
+        viewer.render();
+
+        let key = if viewer.paused || viewer.finished {
+            keys.recv().map_err(|_| RecvTimeoutError::Disconnected)
+        } else {
+            keys.recv_timeout(viewer.delay)
+        };
+
+        match key {
+            Ok(key) => {
+                if !viewer.handle(key) {
+                    break;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => viewer.step(),
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::json::{self, Value};
+
+// Puzzles unlock at midnight EST
+const UNLOCK_HOUR_UTC: i64 = 5;
+
+pub struct Star {
+    pub day: u8,
+    pub part: u8,
+    pub timestamp: i64,
+}
+
+pub struct Member {
+    pub id: i64,
+    pub name: String,
+    pub local_score: i64,
+    /// Stars in the order they were collected
+    pub stars: Vec<Star>,
+}
+
+pub struct Leaderboard {
+    pub event: i64,
+    /// Members in ranking order
+    pub members: Vec<Member>,
+}
+
+/// Days since 1970-01-01 of a date of the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+fn format_duration(seconds: i64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+fn parse_member(value: &Value) -> Result<Member, String> {
+    let id = value
+        .get("id")
+        .and_then(Value::as_i64)
+        .ok_or("Member without id")?;
+    let name = match value.get("name").and_then(Value::as_str) {
+        Some(name) => name.to_string(),
+        None => format!("(anonymous user #{})", id),
+    };
+    let local_score = value
+        .get("local_score")
+        .and_then(Value::as_i64)
+        .unwrap_or(0);
+
+    let mut stars = Vec::new();
+    let days = value
+        .get("completion_day_level")
+        .and_then(Value::as_object)
+        .unwrap_or(&[]);
+    for (day, parts) in days {
+        for (part, star) in parts.as_object().unwrap_or(&[]) {
+            let timestamp = star
+                .get("get_star_ts")
+                .and_then(Value::as_i64)
+                .ok_or_else(|| format!("Star of {} without timestamp", name))?;
+            stars.push(Star {
+                day: day.parse().map_err(|_| format!("Invalid day {}", day))?,
+                part: part.parse().map_err(|_| format!("Invalid part {}", part))?,
+                timestamp,
+            });
+        }
+    }
+    stars.sort_by_key(|star| (star.timestamp, star.day, star.part));
+
+    Ok(Member {
+        id,
+        name,
+        local_score,
+        stars,
+    })
+}
+
+impl Member {
+    pub fn star(&self, day: u8, part: u8) -> Option<&Star> {
+        self.stars.iter().find(|s| s.day == day && s.part == part)
+    }
+
+    /// Time between collecting the two stars of the day.
+    pub fn delta(&self, day: u8) -> Option<i64> {
+        Some(self.star(day, 2)?.timestamp - self.star(day, 1)?.timestamp)
+    }
+
+    fn last_star(&self) -> i64 {
+        self.stars.last().map_or(i64::MAX, |star| star.timestamp)
+    }
+}
+
+impl Leaderboard {
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        let root = json::parse(text)?;
+
+        let event = root
+            .get("event")
+            .and_then(|event| event.as_str()?.parse().ok())
+            .ok_or("Missing event")?;
+
+        let mut members = root
+            .get("members")
+            .and_then(Value::as_object)
+            .ok_or("Missing members")?
+            .iter()
+            .map(|(_, member)| parse_member(member))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Ties are won by the one who got there first
+        members.sort_by_key(|m| (-m.local_score, m.last_star(), m.id));
+
+        Ok(Self { event, members })
+    }
+
+    pub fn unlock_time(&self, day: u8) -> i64 {
+        (days_from_civil(self.event, 12, day as i64) * 24 + UNLOCK_HOUR_UTC) * 3600
+    }
+
+    fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self
+            .members
+            .iter()
+            .flat_map(|m| m.stars.iter().map(|s| s.day))
+            .collect();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+
+    /// Members having both stars of the day with their deltas, fastest first.
+    pub fn deltas(&self, day: u8) -> Vec<(&Member, i64)> {
+        let mut deltas: Vec<_> = self
+            .members
+            .iter()
+            .filter_map(|m| Some((m, m.delta(day)?)))
+            .collect();
+        deltas.sort_by_key(|(m, delta)| (*delta, m.id));
+        deltas
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut res = format!("Leaderboard {}\n\nRanking\n", self.event);
+        for (rank, member) in self.members.iter().enumerate() {
+            res += &format!(
+                "{:3}) {:5} {:3}* {}\n",
+                rank + 1,
+                member.local_score,
+                member.stars.len(),
+                member.name
+            );
+        }
+
+        res += "\nStar timelines\n";
+        for member in &self.members {
+            res += &format!("{}\n", member.name);
+            for star in &member.stars {
+                res += &format!(
+                    "  Day {:2} part {}  {:>10}\n",
+                    star.day,
+                    star.part,
+                    format_duration(star.timestamp - self.unlock_time(star.day))
+                );
+            }
+        }
+
+        res += "\nPart 1 -> part 2 deltas\n";
+        for day in self.days() {
+            let deltas: Vec<String> = self
+                .deltas(day)
+                .iter()
+                .map(|(m, delta)| format!("{} {}", m.name, format_duration(*delta)))
+                .collect();
+            if deltas.is_empty() {
+                res += &format!("Day {:2}: -\n", day);
+            } else {
+                res += &format!("Day {:2}: {}\n", day, deltas.join(", "));
+            }
+        }
+
+        res
+    }
+
+    pub fn to_json(&self) -> String {
+        let ranking: Vec<String> = self
+            .members
+            .iter()
+            .enumerate()
+            .map(|(rank, m)| {
+                format!(
+                    "{{\"rank\":{},\"id\":{},\"name\":{},\"local_score\":{},\"stars\":{}}}",
+                    rank + 1,
+                    m.id,
+                    json::string(&m.name),
+                    m.local_score,
+                    m.stars.len()
+                )
+            })
+            .collect();
+
+        let timelines: Vec<String> = self
+            .members
+            .iter()
+            .map(|m| {
+                let stars: Vec<String> = m
+                    .stars
+                    .iter()
+                    .map(|s| {
+                        format!(
+                            "{{\"day\":{},\"part\":{},\"timestamp\":{},\"since_unlock\":{}}}",
+                            s.day,
+                            s.part,
+                            s.timestamp,
+                            s.timestamp - self.unlock_time(s.day)
+                        )
+                    })
+                    .collect();
+                format!("{{\"id\":{},\"stars\":[{}]}}", m.id, stars.join(","))
+            })
+            .collect();
+
+        let deltas: Vec<String> = self
+            .days()
+            .into_iter()
+            .map(|day| {
+                let members: Vec<String> = self
+                    .deltas(day)
+                    .iter()
+                    .map(|(m, delta)| format!("{{\"id\":{},\"seconds\":{}}}", m.id, delta))
+                    .collect();
+                format!("{{\"day\":{},\"members\":[{}]}}", day, members.join(","))
+            })
+            .collect();
+
+        format!(
+            "{{\"event\":{},\"ranking\":[{}],\"timelines\":[{}],\"deltas\":[{}]}}",
+            self.event,
+            ranking.join(","),
+            timelines.join(","),
+            deltas.join(",")
+        )
+    }
+}
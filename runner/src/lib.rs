@@ -0,0 +1,15 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+pub mod answer;
+pub mod image;
+pub mod leaderboard;
+pub mod puzzle;
+pub mod registry;
+pub mod report;
+pub mod simulation;
+pub mod terminal;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: common::alloc::CountingAllocator = common::alloc::CountingAllocator;
@@ -0,0 +1,108 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use common::budget::Budget;
+use runner::registry::{load_input, parts, Solution};
+
+// Options consuming the next argument, see Budget::from_args
+const OPTIONS_WITH_VALUE: [&str; 4] = ["--input", "--time-limit", "--step-limit", "--progress"];
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+fn print_solution(solution: &Solution, json: bool) {
+    if json {
+        println!("{}", solution.to_json());
+    } else if let Some(memory) = solution.memory {
+        let phases: String = solution
+            .phases
+            .iter()
+            .map(|phase| {
+                format!(
+                    "; {}: {} peak, {} allocations",
+                    phase.name,
+                    format_bytes(phase.stats.peak_bytes),
+                    phase.stats.allocations
+                )
+            })
+            .collect();
+        println!(
+            "Part {}: {} ({:.3?}, {} peak, {} allocations{})",
+            solution.part,
+            solution.outcome,
+            solution.elapsed,
+            format_bytes(memory.peak_bytes),
+            memory.allocations,
+            phases
+        );
+    } else {
+        println!(
+            "Part {}: {} ({:.3?})",
+            solution.part, solution.outcome, solution.elapsed
+        );
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let mut positional = Vec::new();
+    let mut input_path = None;
+    let mut index = 0;
+    while index < args.len() {
+        if args[index] == "--input" {
+            input_path = args.get(index + 1).cloned();
+        }
+
+        if OPTIONS_WITH_VALUE.contains(&args[index].as_str()) {
+            index += 2;
+        } else {
+            if !args[index].starts_with("--") {
+                positional.push(args[index].parse::<u8>().unwrap_or_else(|_| {
+                    panic!("Usage: runner [day [part]] [--input <file>] [--json]")
+                }));
+            }
+            index += 1;
+        }
+    }
+
+    let json = args.iter().any(|arg| arg == "--json");
+    let days: Vec<u8> = match positional.first() {
+        Some(day) => vec![*day],
+        None => (1..=25).collect(),
+    };
+
+    for day in days {
+        let input = match &input_path {
+            Some(path) => std::fs::read_to_string(path)
+                .unwrap_or_else(|e| panic!("Failed to read input {}: {}", path, e)),
+            None => load_input(day),
+        };
+
+        let solvers: Vec<_> = parts(day)
+            .filter(|s| positional.get(1).is_none_or(|part| s.part == *part))
+            .collect();
+
+        if solvers.is_empty() {
+            panic!("No solver for day {} {:?}", day, positional.get(1));
+        }
+
+        if !json {
+            println!("Day {:02}", day);
+        }
+
+        for solver in solvers {
+            print_solution(&solver.run(&input, &mut Budget::from_args()), json);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+/// Examples and sample answers found in a saved puzzle description page.
+pub struct Puzzle {
+    /// Contents of the `<pre><code>` blocks in order of appearance
+    pub examples: Vec<String>,
+    /// Sample answer of each part whose description is on the page
+    pub answers: Vec<String>,
+}
+
+/// Parts of `html` between the `open` and `close` markers, the markers
+/// themselves excluded.
+fn between<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut res = Vec::new();
+
+    let mut rest = html;
+    while let Some(start) = rest.find(open) {
+        rest = &rest[start + open.len()..];
+        match rest.find(close) {
+            Some(end) => {
+                res.push(&rest[..end]);
+                rest = &rest[end + close.len()..];
+            }
+            None => break,
+        }
+    }
+
+    res
+}
+
+fn unescape(text: &str) -> String {
+    let mut res = String::new();
+
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        res.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| &rest[1..end]);
+        let c = match entity {
+            Some("lt") => Some('<'),
+            Some("gt") => Some('>'),
+            Some("amp") => Some('&'),
+            Some("quot") => Some('"'),
+            Some("apos") => Some('\''),
+            Some(e) if e.starts_with("#x") => u32::from_str_radix(&e[2..], 16)
+                .ok()
+                .and_then(char::from_u32),
+            Some(e) if e.starts_with('#') => e[1..].parse().ok().and_then(char::from_u32),
+            _ => None,
+        };
+
+        match (c, entity) {
+            (Some(c), Some(entity)) => {
+                res.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                res.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    res.push_str(rest);
+
+    res
+}
+
+/// Text content of an HTML fragment.
+fn text(fragment: &str) -> String {
+    let mut res = String::new();
+
+    let mut in_tag = false;
+    for c in fragment.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => res.push(c),
+            _ => {}
+        }
+    }
+
+    unescape(&res)
+}
+
+/// The sample answer is the last emphasized code of the part's description.
+fn answer(article: &str) -> Option<String> {
+    let last = |open: &str, close: &str| {
+        let start = article.rfind(open)?;
+        between(&article[start..], open, close)
+            .first()
+            .map(|answer| (start, *answer))
+    };
+
+    [
+        last("<code><em>", "</em></code>"),
+        last("<em><code>", "</code></em>"),
+    ]
+    .into_iter()
+    .flatten()
+    .max_by_key(|(position, _)| *position)
+    .map(|(_, answer)| text(answer))
+}
+
+pub fn parse_page(html: &str) -> Puzzle {
+    let articles = between(html, "<article class=\"day-desc\">", "</article>");
+
+    let mut examples: Vec<String> = Vec::new();
+    for block in articles
+        .iter()
+        .flat_map(|article| between(article, "<pre><code>", "</code></pre>"))
+    {
+        // The blocks end with a new line, which is dropped as solvers get
+        // their inputs without it anyway, see Solver::run
+        let example = text(block).trim_end_matches('\n').to_string();
+        if !examples.contains(&example) {
+            examples.push(example);
+        }
+    }
+
+    let answers = articles
+        .iter()
+        .filter_map(|article| answer(article))
+        .collect();
+
+    Puzzle { examples, answers }
+}
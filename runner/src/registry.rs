@@ -0,0 +1,213 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::time::{Duration, Instant};
+
+use common::{
+    alloc::{self, AllocStats, Phase},
+    budget::{Budget, Outcome},
+    json,
+    memo::CacheConfig,
+    overflow::Overflow,
+};
+
+use crate::answer::Answer;
+
+pub type SolveFn = fn(&str, &mut Budget) -> Outcome<Answer>;
+
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    pub solve: SolveFn,
+}
+
+const fn solver(day: u8, part: u8, solve: SolveFn) -> Solver {
+    Solver { day, part, solve }
+}
+
+fn done<T: Into<Answer>>(value: T) -> Outcome<Answer> {
+    Outcome::Finished(value.into())
+}
+
+fn checked<T: Into<Answer>>(result: Result<Outcome<T>, Overflow>) -> Outcome<Answer> {
+    match result {
+        Ok(outcome) => outcome.map(Into::into),
+        Err(e) => done(Answer::Error(e.to_string())),
+    }
+}
+
+// Puzzle specific parameters are the ones of the real inputs
+pub static SOLVERS: &[Solver] = &[
+    solver(1, 1, |input, _| {
+        done(day01::part12::<u64>(input).map(|p| p.0))
+    }),
+    solver(1, 2, |input, _| {
+        done(day01::part12::<u64>(input).map(|p| p.1))
+    }),
+    solver(2, 1, |input, _| done(day02::part12(input).0)),
+    solver(2, 2, |input, _| done(day02::part12(input).1)),
+    solver(3, 1, |input, _| done(day03::part12(input).0)),
+    solver(3, 2, |input, _| done(day03::part12(input).1)),
+    solver(4, 1, |input, _| done(day04::part12(input).0)),
+    solver(4, 2, |input, _| done(day04::part12(input).1)),
+    solver(5, 1, |input, _| done(day05::part12(input, false))),
+    solver(5, 2, |input, _| done(day05::part12(input, true))),
+    solver(6, 1, |input, _| done(day06::part12(input, 4))),
+    solver(6, 2, |input, _| done(day06::part12(input, 14))),
+    solver(7, 1, |input, _| done(day07::part12(input).0)),
+    solver(7, 2, |input, _| done(day07::part12(input).1)),
+    solver(8, 1, |input, _| done(day08::part1(input))),
+    solver(8, 2, |input, _| done(day08::part2(input))),
+    solver(9, 1, |input, _| done(day09::part12(input, 2))),
+    solver(9, 2, |input, _| done(day09::part12(input, 10))),
+    solver(10, 1, |input, _| done(day10::part12(input).0)),
+    solver(10, 2, |input, _| done(day10::part12(input).1)),
+    solver(11, 1, |input, _| {
+        let monkeys = alloc::phase("parse", || day11::parse_input(input));
+        done(day11::part12(monkeys, 20, 3))
+    }),
+    solver(11, 2, |input, _| {
+        let monkeys = alloc::phase("parse", || day11::parse_input(input));
+        done(day11::part12(monkeys, 10000, 1))
+    }),
+    solver(12, 1, |input, _| done(day12::part12(input).0)),
+    solver(12, 2, |input, _| done(day12::part12(input).1)),
+    solver(13, 1, |input, _| done(day13::part1(input))),
+    solver(13, 2, |input, _| done(day13::part2(input))),
+    solver(14, 1, |input, _| done(day14::part1(input))),
+    solver(14, 2, |input, _| done(day14::part2(input))),
+    solver(15, 1, |input, _| done(day15::part1(input, 2000000))),
+    solver(15, 2, |input, _| done(day15::part2(input, 4000000))),
+    solver(16, 1, |input, budget| {
+        day16::part1(input, budget).map(Answer::from)
+    }),
+    solver(16, 2, |input, budget| {
+        day16::part2(input, budget).map(Answer::from)
+    }),
+    solver(17, 1, |input, _| done(day17::part1(input, 2022))),
+    solver(17, 2, |input, _| done(day17::part2(input, 1000000000000))),
+    solver(18, 1, |input, _| done(day18::part1(input))),
+    solver(18, 2, |input, _| done(day18::part2(input))),
+    solver(19, 1, |input, budget| {
+        checked(day19::part1(input, budget, &CacheConfig::default()))
+    }),
+    solver(19, 2, |input, budget| {
+        checked(
+            day19::part2(input, budget, &CacheConfig::default())
+                .map(|outcome| outcome.map(|max_geodes| max_geodes.iter().product::<u64>())),
+        )
+    }),
+    solver(20, 1, |input, _| done(day20::part1(input, 1, 1))),
+    solver(20, 2, |input, _| done(day20::part1(input, 811589153, 10))),
+    solver(21, 1, |input, _| done(day21::part1(input))),
+    solver(21, 2, |input, _| done(day21::part2(input))),
+    solver(22, 1, |input, _| {
+        done(day22::part12(input, false, vec![2, 1, 4]))
+    }),
+    solver(22, 2, |input, _| {
+        done(day22::part12(input, true, vec![2, 1, 4]))
+    }),
+    solver(23, 1, |input, _| done(day23::part12(input).0)),
+    solver(23, 2, |input, _| done(day23::part12(input).1)),
+    solver(24, 1, |input, _| done(day24::part12(input, false))),
+    solver(24, 2, |input, _| done(day24::part12(input, true))),
+    solver(25, 1, |input, _| {
+        done(day25::part1(input).map(day25::number_to_snafu))
+    }),
+];
+
+pub fn find(day: u8, part: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.day == day && s.part == part)
+}
+
+pub fn parts(day: u8) -> impl Iterator<Item = &'static Solver> {
+    SOLVERS.iter().filter(move |s| s.day == day)
+}
+
+/// Manifest directory of a day crate.
+pub fn day_dir(day: u8) -> String {
+    format!("{}/../day{:02}", env!("CARGO_MANIFEST_DIR"), day)
+}
+
+/// Puzzle input of a day, the same file the day's own binary reads.
+pub fn load_input(day: u8) -> String {
+    common::input::load(&format!("day{:02}", day), &day_dir(day), "input")
+}
+
+pub struct Solution {
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome<Answer>,
+    pub elapsed: Duration,
+    /// Only tracked with the `alloc-stats` feature
+    pub memory: Option<AllocStats>,
+    /// Parts of the run the solver measures separately, like parsing
+    pub phases: Vec<Phase>,
+}
+
+impl Solution {
+    pub fn to_json(&self) -> String {
+        let memory = match self.memory {
+            Some(memory) => {
+                let phases: Vec<String> = self
+                    .phases
+                    .iter()
+                    .map(|phase| {
+                        format!(
+                            "{{\"name\":{},\"peak_bytes\":{},\"allocations\":{}}}",
+                            json::string(phase.name),
+                            phase.stats.peak_bytes,
+                            phase.stats.allocations
+                        )
+                    })
+                    .collect();
+                format!(
+                    ",\"peak_bytes\":{},\"allocations\":{},\"phases\":[{}]",
+                    memory.peak_bytes,
+                    memory.allocations,
+                    phases.join(",")
+                )
+            }
+            None => String::new(),
+        };
+
+        format!(
+            "{{\"day\":{},\"part\":{},\"finished\":{},\"answer\":{},\"time_ms\":{:.3}{}}}",
+            self.day,
+            self.part,
+            self.outcome.is_finished(),
+            self.outcome.clone().value().to_json(),
+            self.elapsed.as_secs_f64() * 1000.0,
+            memory
+        )
+    }
+}
+
+impl Solver {
+    pub fn run(&self, input: &str, budget: &mut Budget) -> Solution {
+        // Several days split on new lines without skipping the empty last
+        // one, so a final new line, as posted to the server, is dropped
+        let input = input.trim_end_matches('\n');
+
+        let start = Instant::now();
+        let (outcome, memory, phases) = alloc::measure_phases(|| (self.solve)(input, budget));
+        let elapsed = start.elapsed();
+
+        Solution {
+            day: self.day,
+            part: self.part,
+            outcome,
+            elapsed,
+            memory: cfg!(feature = "alloc-stats").then_some(memory),
+            phases: if cfg!(feature = "alloc-stats") {
+                phases
+            } else {
+                Vec::new()
+            },
+        }
+    }
+}
+
+pub fn error_json(message: &str) -> String {
+    format!("{{\"error\":{}}}", json::string(message))
+}
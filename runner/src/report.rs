@@ -0,0 +1,330 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::collections::BTreeSet;
+
+use crate::{
+    answer::Answer,
+    image::{Color, Image},
+    registry::{day_dir, Solution},
+};
+
+const BACKGROUND: Color = [15, 15, 35];
+const ROCK: Color = [120, 120, 120];
+const HIGHLIGHT: Color = [255, 255, 102];
+const MARK: Color = [230, 60, 60];
+
+// Rows of the tower shown for day17
+const TOWER_ROWS: u64 = 200;
+// Slices per row for day18
+const SLICE_COLUMNS: usize = 6;
+
+pub struct Visualization {
+    pub caption: String,
+    pub image: Image,
+}
+
+fn visualization(caption: &str, image: Image) -> Vec<Visualization> {
+    vec![Visualization {
+        caption: caption.to_string(),
+        image,
+    }]
+}
+
+fn gradient(value: usize, max: usize) -> Color {
+    let level = (40 + value * 215 / max.max(1)) as u8;
+    [level / 3, level, level / 2]
+}
+
+fn droplet_slices(input: &str) -> Image {
+    let cubes = day18::parse_input(input);
+
+    let min = |axis: fn(&(i64, i64, i64)) -> i64| cubes.iter().map(axis).min().unwrap_or(0);
+    let (min_x, min_y, min_z) = (min(|c| c.0), min(|c| c.1), min(|c| c.2));
+    let cubes: BTreeSet<(usize, usize, usize)> = cubes
+        .iter()
+        .map(|c| {
+            (
+                (c.0 - min_x) as usize,
+                (c.1 - min_y) as usize,
+                (c.2 - min_z) as usize,
+            )
+        })
+        .collect();
+
+    let width = cubes.iter().map(|c| c.0).max().unwrap_or(0) + 1;
+    let height = cubes.iter().map(|c| c.1).max().unwrap_or(0) + 1;
+    let depth = cubes.iter().map(|c| c.2).max().unwrap_or(0) + 1;
+
+    // Slices laid out in a grid, separated by a pixel
+    let rows = depth.div_ceil(SLICE_COLUMNS);
+    let mut image = Image::new(
+        SLICE_COLUMNS * (width + 1) - 1,
+        rows * (height + 1) - 1,
+        [0, 0, 0],
+    );
+    for z in 0..depth {
+        let (left, top) = (
+            (z % SLICE_COLUMNS) * (width + 1),
+            (z / SLICE_COLUMNS) * (height + 1),
+        );
+        for y in 0..height {
+            for x in 0..width {
+                let color = if cubes.contains(&(x, y, z)) {
+                    gradient(z, depth)
+                } else {
+                    BACKGROUND
+                };
+                image.set(left + x, top + y, color);
+            }
+        }
+    }
+
+    image
+}
+
+/// Images of the puzzle states of the visual days.
+pub fn visualizations(day: u8, input: &str) -> Vec<Visualization> {
+    match day {
+        8 => visualization(
+            "Tree heights",
+            Image::from_text(input, BACKGROUND, |c| {
+                gradient(c.to_digit(10).unwrap_or(0) as usize, 9)
+            }),
+        ),
+        10 => visualization(
+            "CRT",
+            Image::from_text(&day10::part12(input).1.join("\n"), BACKGROUND, |c| {
+                if c == '#' {
+                    HIGHLIGHT
+                } else {
+                    BACKGROUND
+                }
+            }),
+        ),
+        12 => visualization(
+            "Heightmap with the start and the best signal location",
+            Image::from_text(input, BACKGROUND, |c| match c {
+                'S' | 'E' => MARK,
+                c if c.is_ascii_lowercase() => gradient(c as usize - 'a' as usize, 25),
+                _ => BACKGROUND,
+            }),
+        ),
+        14 => {
+            let mut cave = day14::Cave::new(input, true);
+            while cave.drop_sand() {}
+
+            visualization(
+                "Sand at rest with the floor",
+                Image::from_text(&cave.draw(), BACKGROUND, |c| match c {
+                    '#' => ROCK,
+                    'o' => HIGHLIGHT,
+                    '+' => MARK,
+                    _ => BACKGROUND,
+                }),
+            )
+        }
+        17 => {
+            let mut chamber = day17::Chamber::new(input);
+            for _ in 0..2022 {
+                chamber.drop_rock();
+            }
+
+            visualization(
+                &format!("Top {} rows of the tower after 2022 rocks", TOWER_ROWS),
+                Image::from_text(&chamber.draw(TOWER_ROWS), BACKGROUND, |c| match c {
+                    '#' => HIGHLIGHT,
+                    '|' | '-' | '+' => ROCK,
+                    _ => BACKGROUND,
+                }),
+            )
+        }
+        18 => visualization("Slices of the droplet along z", droplet_slices(input)),
+        23 => {
+            let mut grove = day23::Grove::new(input);
+            for _ in 0..10 {
+                grove.round();
+            }
+
+            visualization(
+                "Elves after 10 rounds",
+                Image::from_text(&grove.draw(), BACKGROUND, |c| {
+                    if c == '#' {
+                        HIGHLIGHT
+                    } else {
+                        BACKGROUND
+                    }
+                }),
+            )
+        }
+        24 => {
+            let valley = day24::Valley::new(input);
+            let mut image = Image::from_text(&valley.draw(), BACKGROUND, |c| match c {
+                '#' => ROCK,
+                '.' => BACKGROUND,
+                _ => [70, 110, 200],
+            });
+            for (x, y) in day24::expedition(input, false) {
+                image.set(x, y, MARK);
+            }
+
+            visualization(
+                "Blizzards at the start with the tiles the expedition visits",
+                image,
+            )
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Single line form of an answer as stored in the expected answer files,
+/// rows of multi-line answers are separated by `|`.
+pub fn answer_line(answer: &Answer) -> String {
+    match answer {
+        Answer::Lines(lines) => lines.join("|"),
+        answer => answer.to_string(),
+    }
+}
+
+/// Expected answers of the real input, one line per part.
+pub fn expected_answers(day: u8) -> Vec<String> {
+    let path = common::input::path(&format!("day{:02}", day), &day_dir(day), "answers");
+
+    std::fs::read_to_string(path)
+        .map(|answers| answers.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+pub struct DayReport {
+    pub day: u8,
+    pub solutions: Vec<Solution>,
+    pub expected: Vec<String>,
+    pub visualizations: Vec<Visualization>,
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn status(solution: &Solution, expected: Option<&String>) -> (&'static str, &'static str) {
+    if !solution.outcome.is_finished() {
+        return ("timed out", "timeout");
+    }
+
+    match expected {
+        Some(expected) if *expected == answer_line(&solution.outcome.clone().value()) => {
+            ("pass", "pass")
+        }
+        Some(_) => ("fail", "fail"),
+        None => ("no expected answer", "unknown"),
+    }
+}
+
+const STYLE: &str = "
+body { font-family: sans-serif; background: #0f0f23; color: #cccccc; margin: 2em; }
+h1, h2 { color: #00cc00; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #333340; padding: 0.3em 0.8em; text-align: left; vertical-align: top; }
+td.number { text-align: right; }
+pre { margin: 0; }
+.pass { color: #00cc00; }
+.fail { color: #ff4444; }
+.timeout { color: #ffff66; }
+.unknown { color: #888888; }
+figure { display: inline-block; margin: 0 2em 2em 0; }
+img { image-rendering: pixelated; border: 1px solid #333340; }
+";
+
+pub fn html(days: &[DayReport]) -> String {
+    let solutions: Vec<(&DayReport, &Solution)> = days
+        .iter()
+        .flat_map(|day| day.solutions.iter().map(move |solution| (day, solution)))
+        .collect();
+    let passed = solutions
+        .iter()
+        .filter(|(day, s)| status(s, day.expected.get(s.part as usize - 1)).0 == "pass")
+        .count();
+    let has_memory = solutions.iter().any(|(_, s)| s.memory.is_some());
+
+    let mut res = String::new();
+    res += "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n";
+    res += "<title>Advent of Code 2022</title>\n";
+    res += &format!("<style>{}</style>\n</head>\n<body>\n", STYLE);
+    res += "<h1>Advent of Code 2022</h1>\n";
+    res += &format!(
+        "<p>{} of {} answers match the expected ones, total time {:.3?}.</p>\n",
+        passed,
+        solutions.len(),
+        solutions
+            .iter()
+            .map(|(_, s)| s.elapsed)
+            .sum::<std::time::Duration>()
+    );
+
+    res += "<table>\n<tr><th>Day</th><th>Part</th><th>Answer</th><th>Status</th><th>Time</th>";
+    if has_memory {
+        res +=
+            "<th>Peak memory</th><th>Allocations</th><th>Parse peak</th><th>Parse allocations</th>";
+    }
+    res += "</tr>\n";
+    for (day, solution) in &solutions {
+        let expected = day.expected.get(solution.part as usize - 1);
+        let (status, class) = status(solution, expected);
+        let answer = match solution.outcome.clone().value() {
+            Answer::Lines(lines) => lines.join("\n"),
+            answer => answer.to_string(),
+        };
+
+        res += &format!(
+            "<tr><td>{}</td><td>{}</td><td><pre>{}</pre></td><td class=\"{}\" title=\"{}\">{}</td><td class=\"number\">{:.3?}</td>",
+            day.day,
+            solution.part,
+            escape(&answer),
+            class,
+            escape(&format!("Expected: {}", expected.map_or("-", |e| e.as_str()))),
+            status,
+            solution.elapsed
+        );
+        if has_memory {
+            let memory = solution.memory.unwrap_or_default();
+            let parse = solution
+                .phases
+                .iter()
+                .find(|phase| phase.name == "parse")
+                .map_or(("-".to_string(), "-".to_string()), |phase| {
+                    (
+                        phase.stats.peak_bytes.to_string(),
+                        phase.stats.allocations.to_string(),
+                    )
+                });
+            res += &format!(
+                "<td class=\"number\">{}</td><td class=\"number\">{}</td><td class=\"number\">{}</td><td class=\"number\">{}</td>",
+                memory.peak_bytes, memory.allocations, parse.0, parse.1
+            );
+        }
+        res += "</tr>\n";
+    }
+    res += "</table>\n";
+
+    for day in days.iter().filter(|day| !day.visualizations.is_empty()) {
+        res += &format!("<h2>Day {}</h2>\n", day.day);
+        for visualization in &day.visualizations {
+            // Small images are scaled up to be visible
+            let scale = (600 / visualization.image.width.max(1)).clamp(1, 8);
+            res += &format!(
+                "<figure><img src=\"{}\" width=\"{}\" height=\"{}\" alt=\"{}\"><figcaption>{}</figcaption></figure>\n",
+                visualization.image.to_data_uri(),
+                visualization.image.width * scale,
+                visualization.image.height * scale,
+                escape(&visualization.caption),
+                escape(&visualization.caption)
+            );
+        }
+    }
+
+    res += "</body>\n</html>\n";
+    res
+}
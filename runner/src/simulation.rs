@@ -0,0 +1,127 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+/// A puzzle state which can be advanced and drawn frame by frame.
+pub trait Simulation {
+    /// Advances the simulation by one frame. Returns false once it is over.
+    fn step(&mut self) -> bool;
+    /// Draws the current state. Only its top `rows` rows are shown, so tall
+    /// frames may leave out the rest.
+    fn frame(&self, rows: usize) -> String;
+    fn status(&self) -> String;
+}
+
+struct Sand {
+    cave: day14::Cave,
+}
+
+impl Simulation for Sand {
+    fn step(&mut self) -> bool {
+        self.cave.drop_sand()
+    }
+
+    fn frame(&self, _rows: usize) -> String {
+        self.cave.draw()
+    }
+
+    fn status(&self) -> String {
+        format!("{} units of sand at rest", self.cave.sand_count())
+    }
+}
+
+struct Rocks {
+    chamber: day17::Chamber,
+    rocks: usize,
+    limit: usize,
+}
+
+impl Simulation for Rocks {
+    fn step(&mut self) -> bool {
+        self.chamber.drop_rock();
+        self.rocks += 1;
+        self.rocks < self.limit
+    }
+
+    fn frame(&self, rows: usize) -> String {
+        self.chamber.draw(rows as u64)
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "{} rocks fallen, tower height {}",
+            self.rocks,
+            self.chamber.height()
+        )
+    }
+}
+
+struct Elves {
+    grove: day23::Grove,
+}
+
+impl Simulation for Elves {
+    fn step(&mut self) -> bool {
+        self.grove.round()
+    }
+
+    fn frame(&self, _rows: usize) -> String {
+        self.grove.draw()
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "Round {}, {} empty ground tiles",
+            self.grove.rounds(),
+            self.grove.empty_ground()
+        )
+    }
+}
+
+struct Expedition {
+    valley: day24::Valley,
+    path: Vec<(usize, usize)>,
+    minute: usize,
+}
+
+impl Simulation for Expedition {
+    fn step(&mut self) -> bool {
+        self.valley.step();
+        self.minute += 1;
+        self.minute + 1 < self.path.len()
+    }
+
+    fn frame(&self, _rows: usize) -> String {
+        self.valley.draw_expedition(Some(self.path[self.minute]))
+    }
+
+    fn status(&self) -> String {
+        format!("Minute {} of {}", self.minute, self.path.len() - 1)
+    }
+}
+
+/// Simulation of the day's puzzle, if it has one to watch.
+pub fn simulation(day: u8, part: u8, input: &str) -> Option<Box<dyn Simulation>> {
+    let is_part2 = part == 2;
+
+    let simulation: Box<dyn Simulation> = match day {
+        14 => Box::new(Sand {
+            cave: day14::Cave::new(input, is_part2),
+        }),
+        17 => Box::new(Rocks {
+            chamber: day17::Chamber::new(input),
+            rocks: 0,
+            limit: 2022,
+        }),
+        23 => Box::new(Elves {
+            grove: day23::Grove::new(input),
+        }),
+        24 => Box::new(Expedition {
+            valley: day24::Valley::new(input),
+            path: day24::expedition(input, is_part2),
+            minute: 0,
+        }),
+        _ => return None,
+    };
+
+    Some(simulation)
+}
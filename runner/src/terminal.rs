@@ -0,0 +1,132 @@
+// Copyright (c) 2022, Kis Imre. All rights reserved.
+// SPDX-License-Identifier: MIT
+
+use std::{
+    fs::File,
+    io::{self, Read},
+    process::Command,
+    sync::mpsc::{self, Receiver},
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Char(char),
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+}
+
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(File::open("/dev/tty")?)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(format!("stty {:?} failed", args)));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Puts the terminal into raw mode and the alternate screen until dropped.
+pub struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    pub fn enable() -> io::Result<Self> {
+        let saved = stty(&["-g"])?;
+        stty(&["raw", "-echo"])?;
+
+        // Alternate screen, hidden cursor
+        print!("\x1b[?1049h\x1b[?25l");
+
+        Ok(Self { saved })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        print!("\x1b[?25h\x1b[?1049l");
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+// How long a resize may go unnoticed
+const SIZE_REFRESH: Duration = Duration::from_millis(500);
+
+fn query_size() -> (usize, usize) {
+    stty(&["size"])
+        .ok()
+        .and_then(|size| {
+            let (rows, columns) = size.split_once(' ')?;
+            Some((rows.parse().ok()?, columns.parse().ok()?))
+        })
+        .unwrap_or((24, 80))
+}
+
+/// Rows and columns of the terminal. Querying them runs `stty`, so it's only
+/// repeated every `SIZE_REFRESH` instead of reacting to SIGWINCH, which std
+/// can't catch.
+pub struct TerminalSize {
+    size: (usize, usize),
+    queried: Instant,
+}
+
+impl TerminalSize {
+    pub fn query() -> Self {
+        Self {
+            size: query_size(),
+            queried: Instant::now(),
+        }
+    }
+
+    pub fn get(&mut self) -> (usize, usize) {
+        if self.queried.elapsed() >= SIZE_REFRESH {
+            *self = Self::query();
+        }
+
+        self.size
+    }
+}
+
+/// Reads the key presses on a background thread.
+pub fn keys() -> Receiver<Key> {
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        let mut bytes = io::stdin().lock().bytes().map_while(Result::ok);
+        while let Some(byte) = bytes.next() {
+            let key = match byte {
+                // Escape sequences of the arrow and page keys
+                0x1b => match (bytes.next(), bytes.next()) {
+                    (Some(b'['), Some(b'A')) => Key::Up,
+                    (Some(b'['), Some(b'B')) => Key::Down,
+                    (Some(b'['), Some(b'C')) => Key::Right,
+                    (Some(b'['), Some(b'D')) => Key::Left,
+                    (Some(b'['), Some(b'5')) => {
+                        bytes.next();
+                        Key::PageUp
+                    }
+                    (Some(b'['), Some(b'6')) => {
+                        bytes.next();
+                        Key::PageDown
+                    }
+                    _ => continue,
+                },
+                byte => Key::Char(byte as char),
+            };
+
+            if sender.send(key).is_err() {
+                break;
+            }
+        }
+    });
+
+    receiver
+}